@@ -0,0 +1,225 @@
+//! Driver ages worked out from their dates of birth and the race dates.
+use std::fmt;
+
+use crate::{Driver, DriverRecords, Race, RaceDate, Round};
+
+/// How old someone was on a day, in whole years and the days since their last
+/// birthday. Orders from youngest to oldest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Age {
+    pub years: u32,
+    pub days: u32,
+}
+
+impl Age {
+    /// `None` when `date` is before `birth`.
+    pub fn between(birth: RaceDate, date: RaceDate) -> Option<Age> {
+        if date < birth {
+            return None;
+        }
+        let before_birthday = (date.month(), date.day()) < (birth.month(), birth.day());
+        let years = date.year() - birth.year() - u16::from(before_birthday);
+        let total = date.days_since_epoch() - birth.days_since_epoch();
+        let until_birthday = birth.years_later(years).days_since_epoch() - birth.days_since_epoch();
+        Some(Age {
+            years: u32::from(years),
+            days: u32::try_from(total - until_birthday).ok()?,
+        })
+    }
+
+    /// The age as a fractional number of years, for averaging.
+    pub fn in_years(self) -> f64 {
+        f64::from(self.years) + f64::from(self.days) / 365.25
+    }
+}
+
+impl fmt::Display for Age {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}y {}d", self.years, self.days)
+    }
+}
+
+/// A driver's age at one race of the season.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RaceAge {
+    pub driver: Driver,
+    pub round: Round,
+    pub race: String,
+    pub age: Age,
+}
+
+/// Age records for a season, `None` where no driver qualifies or no dates of
+/// birth are known.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SeasonAges {
+    /// The day of the latest race with results, what standings ages are
+    /// counted up to.
+    pub latest_race: Option<RaceDate>,
+    pub youngest_scorer: Option<RaceAge>,
+    pub oldest_scorer: Option<RaceAge>,
+    pub youngest_winner: Option<RaceAge>,
+    pub oldest_winner: Option<RaceAge>,
+    /// Mean age of the starters, over every race.
+    pub average_age: Option<f64>,
+}
+
+/// Joins each driver's records with the race dates in `races`.
+pub fn season_ages(drivers: &[DriverRecords], races: &[Race]) -> SeasonAges {
+    let mut latest_race = None;
+    let mut entries = Vec::new();
+    for driver in drivers {
+        for record in &driver.records {
+            let Some(race) = races.iter().find(|race| race.round == record.round) else {
+                continue;
+            };
+            latest_race = latest_race.max(Some(race.date));
+            let Some(age) = driver.driver.age_on(race.date) else {
+                continue;
+            };
+            let scored = record.points.get() > 0.0;
+            let won = record.position.get() == 1;
+            let entry = RaceAge {
+                driver: driver.driver.clone(),
+                round: race.round,
+                race: race.name.clone(),
+                age,
+            };
+            entries.push((entry, scored, won));
+        }
+    }
+
+    // the youngest and oldest of the entries `pick` accepts
+    let extremes = |pick: fn(&(RaceAge, bool, bool)) -> bool| {
+        let matching = || {
+            entries
+                .iter()
+                .filter(move |entry| pick(entry))
+                .map(|(entry, ..)| entry)
+        };
+        (
+            matching().min_by_key(|entry| entry.age).cloned(),
+            matching().max_by_key(|entry| entry.age).cloned(),
+        )
+    };
+    let (youngest_scorer, oldest_scorer) = extremes(|(_, scored, _)| *scored);
+    let (youngest_winner, oldest_winner) = extremes(|(_, _, won)| *won);
+    let average_age = (!entries.is_empty()).then(|| {
+        let total = entries
+            .iter()
+            .map(|(entry, ..)| entry.age.in_years())
+            .sum::<f64>();
+        total / entries.len() as f64
+    });
+
+    SeasonAges {
+        latest_race,
+        youngest_scorer,
+        oldest_scorer,
+        youngest_winner,
+        oldest_winner,
+        average_age,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Circuit, Points, Record};
+
+    fn date(value: &str) -> RaceDate {
+        value.to_string().try_into().unwrap()
+    }
+
+    fn driver(family_name: &str, date_of_birth: &str) -> Driver {
+        Driver {
+            id: family_name.to_lowercase(),
+            code: None,
+            given_name: String::new(),
+            family_name: family_name.to_string(),
+            url: None,
+            nationality: None,
+            date_of_birth: Some(date(date_of_birth)),
+        }
+    }
+
+    fn race(round: &str, day: &str) -> Race {
+        Race {
+            round: round.to_string().try_into().unwrap(),
+            name: format!("Race {round}"),
+            url: None,
+            date: date(day),
+            time: None,
+            circuit: Circuit {
+                id: String::new(),
+                name: String::new(),
+                url: None,
+                img: None,
+                location: None,
+            },
+        }
+    }
+
+    fn records(driver: Driver, finishes: &[(&str, &str, &str)]) -> DriverRecords {
+        DriverRecords {
+            driver,
+            records: finishes
+                .iter()
+                .map(|(round, position, points)| Record {
+                    round: round.to_string().try_into().unwrap(),
+                    position: position.to_string().try_into().unwrap(),
+                    points: points.to_string().try_into().unwrap(),
+                    sprint_points: Points::default(),
+                    team: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn counts_years_and_days_since_birthday() {
+        let age = |birth, day| Age::between(date(birth), date(day)).unwrap();
+
+        assert_eq!(
+            age("1997-09-30", "2021-03-28"),
+            Age {
+                years: 23,
+                days: 179
+            }
+        );
+        assert_eq!(age("1997-09-30", "2021-09-30"), Age { years: 24, days: 0 });
+        assert_eq!(age("1996-02-29", "2021-03-01"), Age { years: 25, days: 0 });
+        assert_eq!(Age::between(date("2021-03-28"), date("1997-09-30")), None);
+    }
+
+    #[test]
+    fn finds_season_age_records() {
+        let races = [race("1", "2021-03-28"), race("2", "2021-04-18")];
+        let drivers = [
+            records(
+                driver("Verstappen", "1997-09-30"),
+                &[("1", "2", "18"), ("2", "1", "25")],
+            ),
+            records(
+                driver("Hamilton", "1985-01-07"),
+                &[("1", "1", "25"), ("2", "2", "19")],
+            ),
+            records(
+                driver("Mazepin", "1999-03-02"),
+                &[("1", "18", "0"), ("2", "17", "0")],
+            ),
+        ];
+
+        let ages = season_ages(&drivers, &races);
+
+        let family_name = |age: Option<RaceAge>| age.unwrap().driver.family_name;
+        assert_eq!(ages.latest_race, Some(date("2021-04-18")));
+        assert_eq!(family_name(ages.youngest_scorer), "Verstappen");
+        assert_eq!(family_name(ages.oldest_scorer.clone()), "Hamilton");
+        assert_eq!(ages.oldest_scorer.unwrap().age.years, 36);
+        assert_eq!(family_name(ages.youngest_winner.clone()), "Verstappen");
+        assert_eq!(ages.youngest_winner.unwrap().race, "Race 2");
+        assert_eq!(family_name(ages.oldest_winner), "Hamilton");
+        let average = ages.average_age.unwrap();
+        assert!((26.0..28.0).contains(&average), "{average}");
+    }
+}
@@ -0,0 +1,289 @@
+//! Maps the free-text nationalities and country names ergast uses to ISO 3166
+//! country codes, so the frontends can show flags.
+use std::fmt;
+
+/// Demonyms as ergast spells them for drivers and constructors. Historic
+/// states map to their successors, dual nationalities to the first one.
+const NATIONALITIES: &[(&str, &str)] = &[
+    ("American", "US"),
+    ("American-Italian", "US"),
+    ("Argentine", "AR"),
+    ("Argentine-Italian", "AR"),
+    ("Australian", "AU"),
+    ("Austrian", "AT"),
+    ("Belgian", "BE"),
+    ("Brazilian", "BR"),
+    ("British", "GB"),
+    ("Canadian", "CA"),
+    ("Chilean", "CL"),
+    ("Chinese", "CN"),
+    ("Colombian", "CO"),
+    ("Czech", "CZ"),
+    ("Danish", "DK"),
+    ("Dutch", "NL"),
+    ("East German", "DE"),
+    ("Finnish", "FI"),
+    ("French", "FR"),
+    ("German", "DE"),
+    ("Hong Kong", "HK"),
+    ("Hungarian", "HU"),
+    ("Indian", "IN"),
+    ("Indonesian", "ID"),
+    ("Irish", "IE"),
+    ("Italian", "IT"),
+    ("Japanese", "JP"),
+    ("Liechtensteiner", "LI"),
+    ("Malaysian", "MY"),
+    ("Mexican", "MX"),
+    ("Monegasque", "MC"),
+    ("Moroccan", "MA"),
+    ("New Zealander", "NZ"),
+    ("Polish", "PL"),
+    ("Portuguese", "PT"),
+    ("Rhodesian", "ZW"),
+    ("Russian", "RU"),
+    ("South African", "ZA"),
+    ("Spanish", "ES"),
+    ("Swedish", "SE"),
+    ("Swiss", "CH"),
+    ("Thai", "TH"),
+    ("Uruguayan", "UY"),
+    ("Venezuelan", "VE"),
+];
+
+/// Country names as ergast spells them for circuit locations.
+const COUNTRIES: &[(&str, &str)] = &[
+    ("Argentina", "AR"),
+    ("Australia", "AU"),
+    ("Austria", "AT"),
+    ("Azerbaijan", "AZ"),
+    ("Bahrain", "BH"),
+    ("Belgium", "BE"),
+    ("Brazil", "BR"),
+    ("Canada", "CA"),
+    ("China", "CN"),
+    ("France", "FR"),
+    ("Germany", "DE"),
+    ("Hungary", "HU"),
+    ("India", "IN"),
+    ("Italy", "IT"),
+    ("Japan", "JP"),
+    ("Korea", "KR"),
+    ("Malaysia", "MY"),
+    ("Mexico", "MX"),
+    ("Monaco", "MC"),
+    ("Morocco", "MA"),
+    ("Netherlands", "NL"),
+    ("Portugal", "PT"),
+    ("Qatar", "QA"),
+    ("Russia", "RU"),
+    ("Saudi Arabia", "SA"),
+    ("Singapore", "SG"),
+    ("South Africa", "ZA"),
+    ("Spain", "ES"),
+    ("Sweden", "SE"),
+    ("Switzerland", "CH"),
+    ("Turkey", "TR"),
+    ("UAE", "AE"),
+    ("UK", "GB"),
+    ("USA", "US"),
+    ("United States", "US"),
+    ("Vietnam", "VN"),
+];
+
+/// English names for the codes above, for labels.
+const NAMES: &[(&str, &str)] = &[
+    ("AE", "United Arab Emirates"),
+    ("AR", "Argentina"),
+    ("AT", "Austria"),
+    ("AU", "Australia"),
+    ("AZ", "Azerbaijan"),
+    ("BE", "Belgium"),
+    ("BH", "Bahrain"),
+    ("BR", "Brazil"),
+    ("CA", "Canada"),
+    ("CH", "Switzerland"),
+    ("CL", "Chile"),
+    ("CN", "China"),
+    ("CO", "Colombia"),
+    ("CZ", "Czechia"),
+    ("DE", "Germany"),
+    ("DK", "Denmark"),
+    ("ES", "Spain"),
+    ("FI", "Finland"),
+    ("FR", "France"),
+    ("GB", "United Kingdom"),
+    ("HK", "Hong Kong"),
+    ("HU", "Hungary"),
+    ("ID", "Indonesia"),
+    ("IE", "Ireland"),
+    ("IN", "India"),
+    ("IT", "Italy"),
+    ("JP", "Japan"),
+    ("KR", "South Korea"),
+    ("LI", "Liechtenstein"),
+    ("MA", "Morocco"),
+    ("MC", "Monaco"),
+    ("MX", "Mexico"),
+    ("MY", "Malaysia"),
+    ("NL", "Netherlands"),
+    ("NZ", "New Zealand"),
+    ("PL", "Poland"),
+    ("PT", "Portugal"),
+    ("QA", "Qatar"),
+    ("RU", "Russia"),
+    ("SA", "Saudi Arabia"),
+    ("SE", "Sweden"),
+    ("SG", "Singapore"),
+    ("TH", "Thailand"),
+    ("TR", "Turkey"),
+    ("US", "United States"),
+    ("UY", "Uruguay"),
+    ("VE", "Venezuela"),
+    ("VN", "Vietnam"),
+    ("ZA", "South Africa"),
+    ("ZW", "Zimbabwe"),
+];
+
+/// An ISO 3166-1 alpha-2 country code such as `GB`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Country(&'static str);
+
+impl Country {
+    /// The country for a nationality such as `British`.
+    pub fn from_nationality(nationality: &str) -> Option<Self> {
+        lookup(NATIONALITIES, nationality)
+    }
+
+    /// The country for a name such as `UK` or `Italy`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        lookup(COUNTRIES, name)
+    }
+
+    pub fn code(self) -> &'static str {
+        self.0
+    }
+
+    /// The English name such as `United Kingdom`, or the code when there
+    /// isn't one.
+    pub fn name(self) -> &'static str {
+        NAMES
+            .iter()
+            .find(|(code, _)| *code == self.0)
+            .map_or(self.0, |(_, name)| name)
+    }
+
+    /// The flag emoji, a pair of regional indicator symbols.
+    pub fn flag(self) -> String {
+        self.0
+            .bytes()
+            .filter_map(|letter| char::from_u32(0x1F1E6 + u32::from(letter - b'A')))
+            .collect()
+    }
+}
+
+impl fmt::Display for Country {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+fn lookup(table: &[(&str, &'static str)], value: &str) -> Option<Country> {
+    let value = value.trim();
+    table
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(value))
+        .map(|(_, code)| Country(code))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // entries from ergast's driver, constructor and circuit lists as
+    // `id<TAB>value`, written out from the records themselves rather than
+    // from the tables above, e.g.
+    //
+    //   curl -s 'https://ergast.com/api/f1/drivers.json?limit=1000' \
+    //     | jq -r '.MRData.DriverTable.Drivers[] | [.driverId, .nationality] | @tsv'
+    //
+    // and likewise `.MRData.ConstructorTable.Constructors[]` with
+    // `.constructorId`, `.MRData.CircuitTable.Circuits[]` with `.circuitId`
+    // and `.Location.country`
+    const ERGAST_DRIVERS: &str = include_str!("../fixtures/ergast_drivers.tsv");
+    const ERGAST_CONSTRUCTORS: &str = include_str!("../fixtures/ergast_constructors.tsv");
+    const ERGAST_CIRCUITS: &str = include_str!("../fixtures/ergast_circuits.tsv");
+
+    fn entries(fixture: &str) -> impl Iterator<Item = (&str, &str)> {
+        fixture
+            .lines()
+            .map(|line| line.split_once('\t').expect("id<TAB>value"))
+    }
+
+    fn is_code(code: &str) -> bool {
+        code.len() == 2 && code.bytes().all(|letter| letter.is_ascii_uppercase())
+    }
+
+    #[test]
+    fn maps_every_ergast_nationality() {
+        for (id, nationality) in entries(ERGAST_DRIVERS).chain(entries(ERGAST_CONSTRUCTORS)) {
+            let country = Country::from_nationality(nationality);
+            assert!(
+                country.is_some_and(|country| is_code(country.code())),
+                "{id}: {nationality}"
+            );
+        }
+    }
+
+    #[test]
+    fn maps_every_ergast_country() {
+        for (id, name) in entries(ERGAST_CIRCUITS) {
+            let country = Country::from_name(name);
+            assert!(
+                country.is_some_and(|country| is_code(country.code())),
+                "{id}: {name}"
+            );
+        }
+    }
+
+    #[test]
+    fn names_every_country() {
+        for (_, code) in NATIONALITIES.iter().chain(COUNTRIES) {
+            assert_ne!(Country(code).name(), *code);
+        }
+        assert_eq!(Country::from_name("UK").unwrap().name(), "United Kingdom");
+    }
+
+    #[test]
+    fn agrees_on_demonyms_and_names() {
+        let pairs = [
+            ("British", "UK"),
+            ("American", "USA"),
+            ("Monegasque", "Monaco"),
+            ("Dutch", "Netherlands"),
+            ("Swiss", "Switzerland"),
+        ];
+        for (nationality, name) in pairs {
+            assert_eq!(
+                Country::from_nationality(nationality),
+                Country::from_name(name)
+            );
+        }
+    }
+
+    #[test]
+    fn ignores_case_and_padding() {
+        assert_eq!(Country::from_nationality(" british ").unwrap().code(), "GB");
+        assert_eq!(Country::from_nationality("Martian"), None);
+        assert_eq!(Country::from_name(""), None);
+    }
+
+    #[test]
+    fn builds_flag_emoji() {
+        assert_eq!(Country::from_name("UK").unwrap().flag(), "🇬🇧");
+        assert_eq!(
+            Country::from_nationality("Monegasque").unwrap().flag(),
+            "🇲🇨"
+        );
+    }
+}
@@ -0,0 +1,752 @@
+//! Domain types built from the query responses, with the fields the frontends
+//! rely on checked and parsed once here rather than at every use.
+use std::fmt;
+
+use crate::{
+    queries::{
+        circuit_catalog, circuit_races, circuits, constructors, drivers, drivers_graph,
+        round_results,
+    },
+    Age, Country, Error,
+};
+
+fn required<T>(value: Option<T>, field: &'static str) -> Result<T, Error> {
+    value.ok_or(Error::Missing(field))
+}
+
+fn parse<T: TryFrom<String, Error = Error>>(
+    value: Option<String>,
+    field: &'static str,
+) -> Result<T, Error> {
+    required(value, field)?.try_into()
+}
+
+fn invalid(kind: &'static str, value: String) -> Error {
+    Error::Invalid { kind, value }
+}
+
+/// The backend sends empty strings for values ergast doesn't have.
+fn present(value: Option<String>) -> Option<String> {
+    value.filter(|value| !value.is_empty())
+}
+
+fn count(value: Option<String>, field: &'static str) -> Result<u32, Error> {
+    let value = required(value, field)?;
+    value.parse().map_err(|_| invalid(field, value))
+}
+
+/// Championship points, which can be fractional after half points races.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Points(f64);
+
+impl Points {
+    pub fn new(points: f64) -> Self {
+        Points(points)
+    }
+
+    pub fn get(self) -> f64 {
+        self.0
+    }
+}
+
+impl std::ops::Add for Points {
+    type Output = Points;
+
+    fn add(self, other: Points) -> Points {
+        Points(self.0 + other.0)
+    }
+}
+
+impl TryFrom<String> for Points {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Error> {
+        match value.parse::<f64>() {
+            Ok(points) if points.is_finite() => Ok(Points(points)),
+            _ => Err(invalid("points", value)),
+        }
+    }
+}
+
+impl fmt::Display for Points {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // whole numbers print without a trailing `.0`
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A finishing or championship position, starting at 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position(u32);
+
+impl Position {
+    pub fn get(self) -> u32 {
+        self.0
+    }
+}
+
+impl TryFrom<String> for Position {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Error> {
+        match value.parse::<u32>() {
+            Ok(position) if position > 0 => Ok(Position(position)),
+            _ => Err(invalid("position", value)),
+        }
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A race's place in the season's calendar, starting at 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Round(u32);
+
+impl Round {
+    pub fn get(self) -> u32 {
+        self.0
+    }
+}
+
+impl TryFrom<String> for Round {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Error> {
+        match value.parse::<u32>() {
+            Ok(round) if round > 0 => Ok(Round(round)),
+            _ => Err(invalid("round", value)),
+        }
+    }
+}
+
+impl fmt::Display for Round {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A calendar day such as the day a race is held, from ergast's `YYYY-MM-DD`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RaceDate {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl RaceDate {
+    pub fn year(self) -> u16 {
+        self.year
+    }
+
+    /// 1 for January.
+    pub fn month(self) -> u8 {
+        self.month
+    }
+
+    pub fn day(self) -> u8 {
+        self.day
+    }
+
+    /// The same day `years` later, a leap day lands on the 1st of March when
+    /// counting days.
+    pub(crate) fn years_later(self, years: u16) -> RaceDate {
+        RaceDate {
+            year: self.year + years,
+            ..self
+        }
+    }
+
+    fn days_in_month(year: u16, month: u8) -> u8 {
+        let first = RaceDate {
+            year,
+            month,
+            day: 1,
+        };
+        let next = match month {
+            12 => first.years_later(1),
+            _ => first,
+        };
+        let next = RaceDate {
+            month: month % 12 + 1,
+            ..next
+        };
+        (next.days_since_epoch() - first.days_since_epoch()) as u8
+    }
+
+    /// Days since 1970-01-01 in the proleptic Gregorian calendar.
+    pub(crate) fn days_since_epoch(self) -> i64 {
+        let (month, day) = (i64::from(self.month), i64::from(self.day));
+        // counting from March puts the leap day at the end of the year
+        let year = i64::from(self.year) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+}
+
+impl TryFrom<String> for RaceDate {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Error> {
+        let mut parts = value.splitn(3, '-');
+        let date = match (parts.next(), parts.next(), parts.next()) {
+            (Some(year), Some(month), Some(day)) => year
+                .parse()
+                .ok()
+                .zip(month.parse().ok())
+                .zip(day.parse().ok()),
+            _ => None,
+        };
+        match date {
+            Some(((year, month @ 1..=12), day))
+                if (1..=RaceDate::days_in_month(year, month)).contains(&day) =>
+            {
+                Ok(RaceDate { year, month, day })
+            }
+            _ => Err(invalid("date", value)),
+        }
+    }
+}
+
+impl fmt::Display for RaceDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Converts a nullable list of nullable items, skipping the null items.
+pub(crate) fn list<T, U>(
+    items: Option<Vec<Option<T>>>,
+    field: &'static str,
+) -> Result<Vec<U>, Error>
+where
+    U: TryFrom<T, Error = Error>,
+{
+    required(items, field)?
+        .into_iter()
+        .flatten()
+        .map(U::try_from)
+        .collect()
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Driver {
+    pub id: String,
+    /// Three letter code, only assigned to drivers since 2000 or so.
+    pub code: Option<String>,
+    pub given_name: String,
+    pub family_name: String,
+    pub url: Option<String>,
+    pub nationality: Option<String>,
+    pub date_of_birth: Option<RaceDate>,
+}
+
+impl Driver {
+    pub fn name(&self) -> String {
+        format!("{} {}", self.given_name, self.family_name)
+    }
+
+    /// The code, or the family name for drivers from before codes.
+    pub fn label(&self) -> &str {
+        self.code.as_deref().unwrap_or(&self.family_name)
+    }
+
+    pub fn country(&self) -> Option<Country> {
+        Country::from_nationality(self.nationality.as_deref()?)
+    }
+
+    /// How old the driver was on `date`, if we know when they were born.
+    pub fn age_on(&self, date: RaceDate) -> Option<Age> {
+        Age::between(self.date_of_birth?, date)
+    }
+}
+
+macro_rules! impl_driver_from {
+    ($($generated:ty),*) => {$(
+        impl TryFrom<$generated> for Driver {
+            type Error = Error;
+
+            fn try_from(driver: $generated) -> Result<Self, Error> {
+                Ok(Driver {
+                    id: required(driver.id, "driver id")?,
+                    code: present(driver.code),
+                    given_name: required(driver.given_name, "driver given name")?,
+                    family_name: required(driver.family_name, "driver family name")?,
+                    url: present(driver.url),
+                    nationality: present(driver.nationality),
+                    date_of_birth: present(driver.date_of_birth)
+                        .map(RaceDate::try_from)
+                        .transpose()?,
+                })
+            }
+        }
+    )*};
+}
+
+impl_driver_from!(
+    drivers::DriversDriverStandingsDriversDriver,
+    drivers_graph::DriversGraphDriversSeasonalRecordsDriversDriver,
+    round_results::RoundResultsDriversSeasonalRecordsDriversDriver
+);
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DriverStanding {
+    pub position: Position,
+    pub points: Points,
+    pub wins: u32,
+    pub driver: Driver,
+}
+
+impl TryFrom<drivers::DriversDriverStandingsDrivers> for DriverStanding {
+    type Error = Error;
+
+    fn try_from(standing: drivers::DriversDriverStandingsDrivers) -> Result<Self, Error> {
+        Ok(DriverStanding {
+            position: parse(standing.position, "driver position")?,
+            points: parse(standing.points, "driver points")?,
+            wins: count(standing.wins, "driver wins")?,
+            driver: required(standing.driver, "driver")?.try_into()?,
+        })
+    }
+}
+
+/// Where a driver finished and the points they scored in each round of a
+/// season they took part in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DriverRecords {
+    pub driver: Driver,
+    pub records: Vec<Record>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    pub round: Round,
+    pub position: Position,
+    pub points: Points,
+    /// Nothing in rounds without a sprint.
+    pub sprint_points: Points,
+    /// The team the driver raced for that round.
+    pub team: Option<Team>,
+}
+
+/// A driver's finish in a single race.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RaceResult {
+    pub position: Position,
+    pub points: Points,
+    pub driver: Driver,
+}
+
+/// The finishing order of `round`, picked out of every driver's records.
+pub fn race_result(drivers: &[DriverRecords], round: Round) -> Vec<RaceResult> {
+    let mut results = drivers
+        .iter()
+        .filter_map(|driver| {
+            let record = driver.records.iter().find(|record| record.round == round)?;
+            Some(RaceResult {
+                position: record.position,
+                points: record.points,
+                driver: driver.driver.clone(),
+            })
+        })
+        .collect::<Vec<RaceResult>>();
+    results.sort_by_key(|result| result.position);
+    results
+}
+
+macro_rules! impl_records_from {
+    ($($generated:ty),*) => {$(
+        impl TryFrom<$generated> for DriverRecords {
+            type Error = Error;
+
+            fn try_from(driver: $generated) -> Result<Self, Error> {
+                Ok(DriverRecords {
+                    driver: required(driver.driver, "driver")?.try_into()?,
+                    records: list(driver.records, "records")?,
+                })
+            }
+        }
+    )*};
+}
+
+impl_records_from!(
+    drivers_graph::DriversGraphDriversSeasonalRecordsDrivers,
+    round_results::RoundResultsDriversSeasonalRecordsDrivers
+);
+
+macro_rules! impl_record_from {
+    ($($generated:ty),*) => {$(
+        impl TryFrom<$generated> for Record {
+            type Error = Error;
+
+            fn try_from(record: $generated) -> Result<Self, Error> {
+                Ok(Record {
+                    round: parse(record.round, "record round")?,
+                    position: parse(record.position, "record position")?,
+                    points: parse(record.points, "record points")?,
+                    sprint_points: match record.sprint_points {
+                        Some(points) => points.try_into()?,
+                        None => Points::default(),
+                    },
+                    team: record.constructor.map(Team::try_from).transpose()?,
+                })
+            }
+        }
+    )*};
+}
+
+impl_record_from!(
+    drivers_graph::DriversGraphDriversSeasonalRecordsDriversRecords,
+    round_results::RoundResultsDriversSeasonalRecordsDriversRecords
+);
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Team {
+    pub id: String,
+    pub name: String,
+    pub url: Option<String>,
+    pub nationality: Option<String>,
+}
+
+impl Team {
+    pub fn country(&self) -> Option<Country> {
+        Country::from_nationality(self.nationality.as_deref()?)
+    }
+}
+
+macro_rules! impl_team_from {
+    ($($generated:ty),*) => {$(
+        impl TryFrom<$generated> for Team {
+            type Error = Error;
+
+            fn try_from(team: $generated) -> Result<Self, Error> {
+                Ok(Team {
+                    id: required(team.id, "team id")?,
+                    name: required(team.name, "team name")?,
+                    url: present(team.url),
+                    nationality: present(team.nationality),
+                })
+            }
+        }
+    )*};
+}
+
+impl_team_from!(
+    constructors::ConstructorsConstructorStandingsTeamsTeam,
+    drivers_graph::DriversGraphDriversSeasonalRecordsDriversRecordsConstructor,
+    round_results::RoundResultsDriversSeasonalRecordsDriversRecordsConstructor
+);
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConstructorStanding {
+    pub position: Position,
+    pub points: Points,
+    pub wins: u32,
+    pub team: Team,
+}
+
+impl TryFrom<constructors::ConstructorsConstructorStandingsTeams> for ConstructorStanding {
+    type Error = Error;
+
+    fn try_from(
+        standing: constructors::ConstructorsConstructorStandingsTeams,
+    ) -> Result<Self, Error> {
+        Ok(ConstructorStanding {
+            position: parse(standing.position, "team position")?,
+            points: parse(standing.points, "team points")?,
+            wins: count(standing.wins, "team wins")?,
+            team: required(standing.team, "team")?.try_into()?,
+        })
+    }
+}
+
+/// Constructor standings with the round they were last updated after.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConstructorStandings {
+    /// `None` before the first race of the season.
+    pub round: Option<Round>,
+    pub teams: Vec<ConstructorStanding>,
+}
+
+impl TryFrom<constructors::ConstructorsConstructorStandings> for ConstructorStandings {
+    type Error = Error;
+
+    fn try_from(standings: constructors::ConstructorsConstructorStandings) -> Result<Self, Error> {
+        Ok(ConstructorStandings {
+            round: present(standings.round).map(Round::try_from).transpose()?,
+            teams: list(standings.teams, "teams")?,
+        })
+    }
+}
+
+/// Driver standings with the round they were last updated after.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DriverStandings {
+    /// `None` before the first race of the season.
+    pub round: Option<Round>,
+    pub drivers: Vec<DriverStanding>,
+}
+
+impl TryFrom<drivers::DriversDriverStandings> for DriverStandings {
+    type Error = Error;
+
+    fn try_from(standings: drivers::DriversDriverStandings) -> Result<Self, Error> {
+        Ok(DriverStandings {
+            round: present(standings.round).map(Round::try_from).transpose()?,
+            drivers: list(standings.drivers, "drivers")?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Schedule {
+    pub season: String,
+    pub races: Vec<Race>,
+}
+
+impl TryFrom<circuits::CircuitsSchedule> for Schedule {
+    type Error = Error;
+
+    fn try_from(schedule: circuits::CircuitsSchedule) -> Result<Self, Error> {
+        Ok(Schedule {
+            season: required(schedule.season, "season")?,
+            races: list(schedule.races, "races")?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Race {
+    pub round: Round,
+    pub name: String,
+    pub url: Option<String>,
+    pub date: RaceDate,
+    pub time: Option<String>,
+    pub circuit: Circuit,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Circuit {
+    pub id: String,
+    pub name: String,
+    pub url: Option<String>,
+    pub img: Option<String>,
+    pub location: Option<Location>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Location {
+    pub locality: String,
+    pub country: String,
+    pub coordinates: Option<Coordinates>,
+}
+
+impl Location {
+    pub fn country_code(&self) -> Option<Country> {
+        Country::from_name(&self.country)
+    }
+}
+
+/// Latitude and longitude in degrees.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Coordinates {
+    pub lat: f64,
+    pub long: f64,
+}
+
+macro_rules! impl_race_from {
+    ($($generated:ty),*) => {$(
+        impl TryFrom<$generated> for Race {
+            type Error = Error;
+
+            fn try_from(race: $generated) -> Result<Self, Error> {
+                Ok(Race {
+                    round: parse(race.round, "race round")?,
+                    name: required(race.race_name, "race name")?,
+                    url: present(race.url),
+                    date: parse(race.date, "race date")?,
+                    time: present(race.time),
+                    circuit: required(race.circuit, "circuit")?.try_into()?,
+                })
+            }
+        }
+    )*};
+}
+
+impl_race_from!(
+    circuits::CircuitsScheduleRaces,
+    circuit_races::CircuitRacesCircuitRacesRaces
+);
+
+macro_rules! impl_circuit_from {
+    ($($generated:ty),*) => {$(
+        impl TryFrom<$generated> for Circuit {
+            type Error = Error;
+
+            fn try_from(circuit: $generated) -> Result<Self, Error> {
+                Ok(Circuit {
+                    id: required(circuit.id, "circuit id")?,
+                    name: required(circuit.circuit_name, "circuit name")?,
+                    url: present(circuit.url),
+                    img: present(circuit.img),
+                    location: circuit.location.map(Location::try_from).transpose()?,
+                })
+            }
+        }
+    )*};
+}
+
+impl_circuit_from!(
+    circuits::CircuitsScheduleRacesCircuit,
+    circuit_catalog::CircuitCatalogCircuitsCircuits,
+    circuit_races::CircuitRacesCircuitRacesRacesCircuit
+);
+
+macro_rules! impl_location_from {
+    ($($generated:ty),*) => {$(
+        impl TryFrom<$generated> for Location {
+            type Error = Error;
+
+            fn try_from(location: $generated) -> Result<Self, Error> {
+                Ok(Location {
+                    locality: required(present(location.locality), "locality")?,
+                    country: required(present(location.country), "country")?,
+                    coordinates: coordinates(location.lat, location.long)?,
+                })
+            }
+        }
+    )*};
+}
+
+impl_location_from!(
+    circuits::CircuitsScheduleRacesCircuitLocation,
+    circuit_catalog::CircuitCatalogCircuitsCircuitsLocation,
+    circuit_races::CircuitRacesCircuitRacesRacesCircuitLocation
+);
+
+fn coordinates(lat: Option<String>, long: Option<String>) -> Result<Option<Coordinates>, Error> {
+    let (Some(lat), Some(long)) = (present(lat), present(long)) else {
+        return Ok(None);
+    };
+    let degrees = |value: String| {
+        value
+            .parse::<f64>()
+            .map_err(|_| invalid("coordinate", value))
+    };
+    Ok(Some(Coordinates {
+        lat: degrees(lat)?,
+        long: degrees(long)?,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse<T: TryFrom<String, Error = Error>>(value: &str) -> Result<T, Error> {
+        T::try_from(value.to_string())
+    }
+
+    #[test]
+    fn parses_points() {
+        assert_eq!(parse::<Points>("395.5").unwrap().get(), 395.5);
+        assert_eq!(parse::<Points>("226").unwrap().to_string(), "226");
+        assert!(parse::<Points>("").is_err());
+        assert!(parse::<Points>("NaN").is_err());
+    }
+
+    #[test]
+    fn parses_positions_and_rounds() {
+        assert_eq!(parse::<Position>("1").unwrap().get(), 1);
+        assert!(parse::<Position>("0").is_err());
+        assert!(parse::<Position>("R").is_err());
+        assert_eq!(parse::<Round>("22").unwrap().get(), 22);
+        assert!(parse::<Round>("-1").is_err());
+    }
+
+    #[test]
+    fn parses_race_dates() {
+        let date = parse::<RaceDate>("2021-03-28").unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (2021, 3, 28));
+        assert_eq!(date.to_string(), "2021-03-28");
+        assert!(parse::<RaceDate>("2021-13-01").is_err());
+        assert!(parse::<RaceDate>("2021-02-31").is_err());
+        assert!(parse::<RaceDate>("2021-04-31").is_err());
+        assert!(parse::<RaceDate>("2021-02-29").is_err());
+        assert!(parse::<RaceDate>("2100-02-29").is_err());
+        assert!(parse::<RaceDate>("2021-03-00").is_err());
+        assert!(parse::<RaceDate>("2020-02-29").is_ok());
+        assert!(parse::<RaceDate>("2000-02-29").is_ok());
+        assert!(parse::<RaceDate>("2021-12-31").is_ok());
+        assert!(parse::<RaceDate>("2021-03").is_err());
+        assert!(parse::<RaceDate>("28/03/2021").is_err());
+    }
+
+    #[test]
+    fn counts_days_since_epoch() {
+        let days = |value: &str| parse::<RaceDate>(value).unwrap().days_since_epoch();
+        assert_eq!(days("1970-01-01"), 0);
+        assert_eq!(days("1969-12-31"), -1);
+        assert_eq!(days("2000-03-01") - days("2000-02-28"), 2);
+        assert_eq!(days("2021-03-28"), 18_714);
+    }
+
+    #[test]
+    fn parses_coordinates() {
+        let some = |value: &str| Some(value.to_string());
+
+        let monza = coordinates(some("45.6156"), some("9.28111"))
+            .unwrap()
+            .unwrap();
+        assert_eq!((monza.lat, monza.long), (45.6156, 9.28111));
+        assert_eq!(coordinates(some(""), some("9.28111")).unwrap(), None);
+        assert!(coordinates(some("north"), some("9.28111")).is_err());
+    }
+
+    fn records(family_name: &str, finishes: &[(&str, &str)]) -> DriverRecords {
+        DriverRecords {
+            driver: Driver {
+                id: family_name.to_lowercase(),
+                code: None,
+                given_name: String::new(),
+                family_name: family_name.to_string(),
+                url: None,
+                nationality: None,
+                date_of_birth: None,
+            },
+            records: finishes
+                .iter()
+                .map(|(round, position)| Record {
+                    round: parse(round).unwrap(),
+                    position: parse(position).unwrap(),
+                    points: Points::default(),
+                    sprint_points: Points::default(),
+                    team: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn orders_race_result_by_position() {
+        let drivers = [
+            records("Verstappen", &[("1", "2"), ("2", "1")]),
+            records("Hamilton", &[("1", "1"), ("2", "2")]),
+            records("Mazepin", &[("2", "17")]),
+        ];
+
+        let result = race_result(&drivers, parse("1").unwrap());
+
+        let order = result
+            .iter()
+            .map(|result| result.driver.label())
+            .collect::<Vec<&str>>();
+        assert_eq!(order, ["Hamilton", "Verstappen"]);
+    }
+}
@@ -0,0 +1,58 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The request couldn't be sent or its body couldn't be decoded.
+    Request(reqwest::Error),
+    /// The response body wasn't the expected shape.
+    Decode(serde_json::Error),
+    /// The transport couldn't answer, e.g. there's no fixture for a query.
+    Transport(String),
+    /// The server answered with GraphQL errors and no data.
+    Response(Vec<graphql_client::Error>),
+    /// A field the domain types rely on was null.
+    Missing(&'static str),
+    /// A field couldn't be parsed into its domain type.
+    Invalid { kind: &'static str, value: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Request(err) => write!(f, "request failed: {err}"),
+            Error::Decode(err) => write!(f, "unexpected response: {err}"),
+            Error::Transport(message) => write!(f, "{message}"),
+            Error::Response(errors) => {
+                let messages = errors
+                    .iter()
+                    .map(|err| err.message.as_str())
+                    .collect::<Vec<&str>>();
+                write!(f, "query failed: {}", messages.join(", "))
+            }
+            Error::Missing(field) => write!(f, "missing {field}"),
+            Error::Invalid { kind, value } => write!(f, "invalid {kind} {value:?}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Request(err) => Some(err),
+            Error::Decode(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Error::Request(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Decode(err)
+    }
+}
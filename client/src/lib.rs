@@ -0,0 +1,231 @@
+//! Client for the formulagraphql API, shared by the web frontend, the
+//! terminal client and anything else that wants standings without writing
+//! GraphQL. Works on native targets and on wasm32 in the browser.
+use futures_util::future::try_join3;
+use graphql_client::{GraphQLQuery, QueryBody, Response};
+use std::sync::Arc;
+
+mod error;
+pub use error::Error;
+mod domain;
+pub use domain::*;
+mod country;
+pub use country::Country;
+mod ages;
+pub use ages::{season_ages, Age, RaceAge, SeasonAges};
+mod summary;
+pub use summary::{season_summary, RoundHaul, SeasonSummary, Tally};
+mod teammates;
+pub use teammates::{teammate_battles, TeammateBattle};
+pub mod queries;
+use queries::{
+    circuit_catalog, circuit_races, circuits, constructors, drivers, drivers_graph, round_results,
+};
+mod transport;
+pub use transport::{BoxFuture, FixtureTransport, HttpTransport, Request, Transport};
+
+#[derive(Clone)]
+pub struct Client {
+    transport: Arc<dyn Transport>,
+}
+
+impl Client {
+    /// `gql_addr` is the server root, queries are posted to `{gql_addr}/query`.
+    pub fn new(gql_addr: impl Into<String>) -> Self {
+        Client::with_transport(HttpTransport::new(gql_addr))
+    }
+
+    pub fn with_transport(transport: impl Transport + 'static) -> Self {
+        Client {
+            transport: Arc::new(transport),
+        }
+    }
+
+    /// Runs any of the generated [`queries`] and returns its raw response data.
+    pub async fn query<Q: GraphQLQuery>(
+        &self,
+        variables: Q::Variables,
+    ) -> Result<Q::ResponseData, Error> {
+        let request_body = Q::build_query(variables);
+        let request = QueryBody {
+            variables: serde_json::to_value(request_body.variables)?,
+            query: request_body.query,
+            operation_name: request_body.operation_name,
+        };
+        let response_body: Response<Q::ResponseData> =
+            serde_json::from_value(self.transport.send(request).await?)?;
+        match (response_body.data, response_body.errors) {
+            (Some(data), _) => Ok(data),
+            (None, Some(errors)) if !errors.is_empty() => Err(Error::Response(errors)),
+            (None, _) => Err(Error::Missing("response data")),
+        }
+    }
+
+    /// Driver standings for `year`, which is a season or `current`.
+    pub async fn driver_standings(&self, year: &str) -> Result<Vec<DriverStanding>, Error> {
+        Ok(self.driver_standings_at(year, None).await?.drivers)
+    }
+
+    /// Driver standings for `year` along with the round they're up to date
+    /// with, to tell when another round has been raced.
+    pub async fn latest_driver_standings(&self, year: &str) -> Result<DriverStandings, Error> {
+        self.driver_standings_at(year, None).await
+    }
+
+    /// Driver standings as they were after `round` of `year`, empty when the
+    /// round hasn't been raced yet.
+    pub async fn driver_standings_after(
+        &self,
+        year: &str,
+        round: Round,
+    ) -> Result<Vec<DriverStanding>, Error> {
+        match self.driver_standings_at(year, Some(round)).await {
+            // the backend has no standings for a round that hasn't been raced
+            Err(Error::Missing("driver standings")) => Ok(Vec::new()),
+            standings => Ok(standings?.drivers),
+        }
+    }
+
+    async fn driver_standings_at(
+        &self,
+        year: &str,
+        round: Option<Round>,
+    ) -> Result<DriverStandings, Error> {
+        let variables = drivers::Variables {
+            year: year.to_string(),
+            round: round.map(|round| round.to_string()),
+        };
+        self.query::<queries::Drivers>(variables)
+            .await?
+            .driver_standings
+            .ok_or(Error::Missing("driver standings"))?
+            .try_into()
+    }
+
+    /// Finishing position and points per round for every driver in `year`.
+    pub async fn driver_records(&self, year: &str) -> Result<Vec<DriverRecords>, Error> {
+        let variables = drivers_graph::Variables {
+            year: year.to_string(),
+        };
+        let records = self
+            .query::<queries::DriversGraph>(variables)
+            .await?
+            .drivers_seasonal_records
+            .ok_or(Error::Missing("driver records"))?;
+        domain::list(records.drivers, "drivers")
+    }
+
+    /// The finishing order of `round` of `year`.
+    pub async fn race_result(&self, year: &str, round: Round) -> Result<Vec<RaceResult>, Error> {
+        let variables = round_results::Variables {
+            year: year.to_string(),
+            round: round.to_string(),
+        };
+        let results = self
+            .query::<queries::RoundResults>(variables)
+            .await?
+            .drivers_seasonal_records
+            .ok_or(Error::Missing("round results"))?;
+        let records = domain::list::<_, DriverRecords>(results.drivers, "drivers")?;
+        Ok(domain::race_result(&records, round))
+    }
+
+    /// Age records of `year`, which is a season or `current`.
+    pub async fn season_ages(&self, year: &str) -> Result<SeasonAges, Error> {
+        let records = self.driver_records(year).await?;
+        let schedule = self.schedule(year).await?;
+        Ok(ages::season_ages(&records, &schedule.races))
+    }
+
+    /// Champions and headline records of `year`, which is a season or
+    /// `current`.
+    pub async fn season_summary(&self, year: &str) -> Result<SeasonSummary, Error> {
+        let (drivers, records, constructors) = try_join3(
+            self.driver_standings(year),
+            self.driver_records(year),
+            self.constructor_standings(year),
+        )
+        .await?;
+        Ok(summary::season_summary(&drivers, &records, &constructors))
+    }
+
+    /// Head-to-heads between the drivers of each team in `year`.
+    pub async fn teammate_battles(&self, year: &str) -> Result<Vec<TeammateBattle>, Error> {
+        let records = self.driver_records(year).await?;
+        Ok(teammates::teammate_battles(&records))
+    }
+
+    /// Constructor standings for `year`, which is a season or `current`.
+    pub async fn constructor_standings(
+        &self,
+        year: &str,
+    ) -> Result<Vec<ConstructorStanding>, Error> {
+        Ok(self.constructor_standings_at(year, None).await?.teams)
+    }
+
+    /// Constructor standings as they were after `round` of `year`, empty when
+    /// the round hasn't been raced yet.
+    pub async fn constructor_standings_after(
+        &self,
+        year: &str,
+        round: Round,
+    ) -> Result<Vec<ConstructorStanding>, Error> {
+        match self.constructor_standings_at(year, Some(round)).await {
+            Err(Error::Missing("constructor standings")) => Ok(Vec::new()),
+            standings => Ok(standings?.teams),
+        }
+    }
+
+    async fn constructor_standings_at(
+        &self,
+        year: &str,
+        round: Option<Round>,
+    ) -> Result<ConstructorStandings, Error> {
+        let variables = constructors::Variables {
+            year: year.to_string(),
+            round: round.map(|round| round.to_string()),
+        };
+        self.query::<queries::Constructors>(variables)
+            .await?
+            .constructor_standings
+            .ok_or(Error::Missing("constructor standings"))?
+            .try_into()
+    }
+
+    /// The races of `year`, which is a season or `current`.
+    pub async fn schedule(&self, year: &str) -> Result<Schedule, Error> {
+        let variables = circuits::Variables {
+            year: year.to_string(),
+        };
+        self.query::<queries::Circuits>(variables)
+            .await?
+            .schedule
+            .ok_or(Error::Missing("schedule"))?
+            .try_into()
+    }
+
+    /// The circuits raced at in `year`, which is a season, `current` or `all`
+    /// for every circuit ever used.
+    pub async fn circuits(&self, year: &str) -> Result<Vec<Circuit>, Error> {
+        let variables = circuit_catalog::Variables {
+            year: year.to_string(),
+        };
+        let catalog = self
+            .query::<queries::CircuitCatalog>(variables)
+            .await?
+            .circuits
+            .ok_or(Error::Missing("circuits"))?;
+        domain::list(catalog.circuits, "circuits")
+    }
+
+    /// Every race held at the circuit with `id`, oldest first.
+    pub async fn circuit_races(&self, id: &str) -> Result<Vec<Race>, Error> {
+        let variables = circuit_races::Variables { id: id.to_string() };
+        let history = self
+            .query::<queries::CircuitRaces>(variables)
+            .await?
+            .circuit_races
+            .ok_or(Error::Missing("circuit races"))?;
+        domain::list(history.races, "races")
+    }
+}
@@ -0,0 +1,58 @@
+//! Queries generated from `graph/query.graphql`.
+use graphql_client::GraphQLQuery;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graph/schema.graphql",
+    query_path = "graph/query.graphql",
+    response_derives = "Debug"
+)]
+pub struct Drivers;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graph/schema.graphql",
+    query_path = "graph/query.graphql",
+    response_derives = "Debug"
+)]
+pub struct DriversGraph;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graph/schema.graphql",
+    query_path = "graph/query.graphql",
+    response_derives = "Debug"
+)]
+pub struct RoundResults;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graph/schema.graphql",
+    query_path = "graph/query.graphql",
+    response_derives = "Debug"
+)]
+pub struct Constructors;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graph/schema.graphql",
+    query_path = "graph/query.graphql",
+    response_derives = "Debug"
+)]
+pub struct Circuits;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graph/schema.graphql",
+    query_path = "graph/query.graphql",
+    response_derives = "Debug"
+)]
+pub struct CircuitCatalog;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graph/schema.graphql",
+    query_path = "graph/query.graphql",
+    response_derives = "Debug"
+)]
+pub struct CircuitRaces;
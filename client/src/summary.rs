@@ -0,0 +1,249 @@
+//! Headline numbers of a season, worked out from its standings and records.
+use std::collections::HashSet;
+
+use crate::{ConstructorStanding, Driver, DriverRecords, DriverStanding, Points, Round};
+
+/// How many times a driver did something in a season.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tally {
+    pub driver: Driver,
+    pub count: u32,
+}
+
+/// The points a driver scored in one round.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoundHaul {
+    pub driver: Driver,
+    pub round: Round,
+    pub points: Points,
+}
+
+/// `None` fields are for seasons that haven't got that far, such as one with
+/// a single driver classified so far.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SeasonSummary {
+    pub champion: Option<DriverStanding>,
+    pub runner_up: Option<DriverStanding>,
+    /// The champion's points lead over the runner-up.
+    pub margin: Option<Points>,
+    pub most_wins: Option<Tally>,
+    pub most_podiums: Option<Tally>,
+    /// The most points anyone scored in a single round.
+    pub best_round: Option<RoundHaul>,
+    /// How many drivers won at least one race.
+    pub winners: u32,
+    pub constructors_champion: Option<ConstructorStanding>,
+}
+
+/// Ties go to whoever is higher in `drivers`, the final standings.
+pub fn season_summary(
+    drivers: &[DriverStanding],
+    records: &[DriverRecords],
+    constructors: &[ConstructorStanding],
+) -> SeasonSummary {
+    let champion = drivers.first().cloned();
+    let runner_up = drivers.get(1).cloned();
+    let margin = champion
+        .as_ref()
+        .zip(runner_up.as_ref())
+        .map(|(champion, runner_up)| Points::new(champion.points.get() - runner_up.points.get()));
+
+    let most_wins = drivers
+        .iter()
+        .rev()
+        .max_by_key(|standing| standing.wins)
+        .filter(|standing| standing.wins > 0)
+        .map(|standing| Tally {
+            driver: standing.driver.clone(),
+            count: standing.wins,
+        });
+
+    let podiums = |driver: &DriverRecords| {
+        let count = driver
+            .records
+            .iter()
+            .filter(|record| record.position.get() <= 3)
+            .count();
+        u32::try_from(count).unwrap_or(u32::MAX)
+    };
+    let most_podiums = by_standing(drivers, records)
+        .rev()
+        .max_by_key(|driver| podiums(driver))
+        .filter(|driver| podiums(driver) > 0)
+        .map(|driver| Tally {
+            driver: driver.driver.clone(),
+            count: podiums(driver),
+        });
+
+    let best_round = by_standing(drivers, records)
+        .flat_map(|driver| {
+            driver.records.iter().map(|record| RoundHaul {
+                driver: driver.driver.clone(),
+                round: record.round,
+                points: record.points,
+            })
+        })
+        .reduce(|best, haul| {
+            if haul.points > best.points {
+                haul
+            } else {
+                best
+            }
+        })
+        .filter(|haul| haul.points.get() > 0.0);
+
+    let winners = records
+        .iter()
+        .filter(|driver| {
+            driver
+                .records
+                .iter()
+                .any(|record| record.position.get() == 1)
+        })
+        .map(|driver| driver.driver.id.as_str())
+        .collect::<HashSet<&str>>()
+        .len();
+
+    SeasonSummary {
+        champion,
+        runner_up,
+        margin,
+        most_wins,
+        most_podiums,
+        best_round,
+        winners: u32::try_from(winners).unwrap_or(u32::MAX),
+        constructors_champion: constructors.first().cloned(),
+    }
+}
+
+/// `records` in the order their drivers finished the championship, drivers
+/// missing from the standings last.
+fn by_standing<'a>(
+    drivers: &'a [DriverStanding],
+    records: &'a [DriverRecords],
+) -> impl DoubleEndedIterator<Item = &'a DriverRecords> {
+    let rank = |driver: &DriverRecords| {
+        drivers
+            .iter()
+            .position(|standing| standing.driver.id == driver.driver.id)
+            .unwrap_or(drivers.len())
+    };
+    let mut sorted = records.iter().collect::<Vec<&DriverRecords>>();
+    sorted.sort_by_key(|driver| rank(driver));
+    sorted.into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Record, Team};
+
+    fn driver(family_name: &str) -> Driver {
+        Driver {
+            id: family_name.to_lowercase(),
+            code: None,
+            given_name: String::new(),
+            family_name: family_name.to_string(),
+            url: None,
+            nationality: None,
+            date_of_birth: None,
+        }
+    }
+
+    fn standing(position: u32, family_name: &str, points: f64, wins: u32) -> DriverStanding {
+        DriverStanding {
+            position: position.to_string().try_into().unwrap(),
+            points: Points::new(points),
+            wins,
+            driver: driver(family_name),
+        }
+    }
+
+    fn records(family_name: &str, finishes: &[(&str, &str, &str)]) -> DriverRecords {
+        DriverRecords {
+            driver: driver(family_name),
+            records: finishes
+                .iter()
+                .map(|(round, position, points)| Record {
+                    round: round.to_string().try_into().unwrap(),
+                    position: position.to_string().try_into().unwrap(),
+                    points: points.to_string().try_into().unwrap(),
+                    sprint_points: Points::default(),
+                    team: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn summarises_season() {
+        let drivers = [
+            standing(1, "Verstappen", 69.0, 1),
+            standing(2, "Hamilton", 62.0, 2),
+            standing(3, "Bottas", 33.0, 0),
+        ];
+        let records = [
+            records(
+                "Hamilton",
+                &[("1", "1", "25"), ("2", "2", "19"), ("3", "1", "18")],
+            ),
+            records(
+                "Bottas",
+                &[("1", "3", "16"), ("2", "19", "0"), ("3", "3", "17")],
+            ),
+            records(
+                "Verstappen",
+                &[("1", "2", "18"), ("2", "1", "26"), ("3", "2", "25")],
+            ),
+        ];
+        let constructors = [ConstructorStanding {
+            position: "1".to_string().try_into().unwrap(),
+            points: Points::new(101.0),
+            wins: 3,
+            team: Team {
+                id: "mercedes".to_string(),
+                name: "Mercedes".to_string(),
+                url: None,
+                nationality: None,
+            },
+        }];
+
+        let summary = season_summary(&drivers, &records, &constructors);
+
+        let family_name = |driver: Option<Driver>| driver.unwrap().family_name;
+        assert_eq!(
+            family_name(summary.champion.map(|s| s.driver)),
+            "Verstappen"
+        );
+        assert_eq!(family_name(summary.runner_up.map(|s| s.driver)), "Hamilton");
+        assert_eq!(summary.margin, Some(Points::new(7.0)));
+        assert_eq!(summary.most_wins.clone().unwrap().count, 2);
+        assert_eq!(family_name(summary.most_wins.map(|t| t.driver)), "Hamilton");
+        // Verstappen and Hamilton both have three podiums, the champion wins
+        // the tie
+        assert_eq!(summary.most_podiums.clone().unwrap().count, 3);
+        assert_eq!(
+            family_name(summary.most_podiums.map(|t| t.driver)),
+            "Verstappen"
+        );
+        let best_round = summary.best_round.unwrap();
+        assert_eq!(best_round.driver.family_name, "Verstappen");
+        assert_eq!(best_round.round.get(), 2);
+        assert_eq!(best_round.points, Points::new(26.0));
+        assert_eq!(summary.winners, 2);
+        assert_eq!(summary.constructors_champion.unwrap().team.name, "Mercedes");
+    }
+
+    #[test]
+    fn leaves_out_what_hasnt_happened() {
+        let summary = season_summary(&[standing(1, "Verstappen", 0.0, 0)], &[], &[]);
+
+        assert!(summary.champion.is_some());
+        assert_eq!(summary.runner_up, None);
+        assert_eq!(summary.margin, None);
+        assert_eq!(summary.most_wins, None);
+        assert_eq!(summary.most_podiums, None);
+        assert_eq!(summary.best_round, None);
+        assert_eq!(summary.winners, 0);
+    }
+}
@@ -0,0 +1,252 @@
+//! Head-to-head comparisons of drivers who raced for the same team.
+use std::collections::BTreeSet;
+
+use crate::{Driver, DriverRecords, Points, Record, Round, Team};
+
+/// Two drivers of one team, counting only the rounds each raced for it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TeammateBattle {
+    pub team: Team,
+    /// The driver who scored more points for the team comes first.
+    pub drivers: [Driver; 2],
+    pub points: [Points; 2],
+    /// Rounds each finished ahead of the other, out of those they both raced.
+    pub ahead: [u32; 2],
+    /// The running points totals after every round either of them raced.
+    pub cumulative: Vec<(Round, [Points; 2])>,
+}
+
+impl TeammateBattle {
+    /// How many rounds the two raced together.
+    pub fn shared_rounds(&self) -> u32 {
+        self.ahead[0] + self.ahead[1]
+    }
+}
+
+/// Every pair of drivers who raced together for a team in a season, teams
+/// with the most points first.
+pub fn teammate_battles(drivers: &[DriverRecords]) -> Vec<TeammateBattle> {
+    let mut teams = Vec::<(Team, Vec<(&Driver, Vec<&Record>)>)>::new();
+    for driver in drivers {
+        for record in &driver.records {
+            let Some(team) = &record.team else {
+                continue;
+            };
+            let index = match teams.iter().position(|(known, _)| known.id == team.id) {
+                Some(index) => index,
+                None => {
+                    teams.push((team.clone(), Vec::new()));
+                    teams.len() - 1
+                }
+            };
+            let line_up = &mut teams[index].1;
+            match line_up
+                .iter_mut()
+                .find(|(known, _)| known.id == driver.driver.id)
+            {
+                Some((_, records)) => records.push(record),
+                None => line_up.push((&driver.driver, vec![record])),
+            }
+        }
+    }
+
+    let mut battles = Vec::new();
+    for (team, line_up) in &teams {
+        for (index, first) in line_up.iter().enumerate() {
+            for second in &line_up[index + 1..] {
+                if let Some(battle) = battle(team, first, second) {
+                    battles.push(battle);
+                }
+            }
+        }
+    }
+    let team_points = |team: &Team| {
+        teams
+            .iter()
+            .find(|(known, _)| known.id == team.id)
+            .map(|(_, line_up)| {
+                line_up
+                    .iter()
+                    .flat_map(|(_, records)| records)
+                    .map(|record| record.points.get())
+                    .sum::<f64>()
+            })
+            .unwrap_or_default()
+    };
+    battles.sort_by(|a, b| team_points(&b.team).total_cmp(&team_points(&a.team)));
+    battles
+}
+
+/// `None` when the two never raced in the same round.
+fn battle(
+    team: &Team,
+    first: &(&Driver, Vec<&Record>),
+    second: &(&Driver, Vec<&Record>),
+) -> Option<TeammateBattle> {
+    let total =
+        |records: &[&Record]| Points::new(records.iter().map(|record| record.points.get()).sum());
+    let (first, second) = if total(&second.1) > total(&first.1) {
+        (second, first)
+    } else {
+        (first, second)
+    };
+
+    let rounds = first
+        .1
+        .iter()
+        .chain(&second.1)
+        .map(|record| record.round)
+        .collect::<BTreeSet<Round>>();
+    let mut ahead = [0, 0];
+    let mut running = [0.0, 0.0];
+    let mut cumulative = Vec::new();
+    for round in rounds {
+        let records = [in_round(&first.1, round), in_round(&second.1, round)];
+        if let [Some(a), Some(b)] = records {
+            ahead[usize::from(b.position < a.position)] += 1;
+        }
+        for (total, record) in running.iter_mut().zip(records) {
+            *total += record.map_or(0.0, |record| record.points.get());
+        }
+        cumulative.push((round, running.map(Points::new)));
+    }
+    if ahead == [0, 0] {
+        return None;
+    }
+
+    Some(TeammateBattle {
+        team: team.clone(),
+        drivers: [first.0.clone(), second.0.clone()],
+        points: [total(&first.1), total(&second.1)],
+        ahead,
+        cumulative,
+    })
+}
+
+fn in_round<'a>(records: &[&'a Record], round: Round) -> Option<&'a Record> {
+    records.iter().find(|record| record.round == round).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn team(name: &str) -> Team {
+        Team {
+            id: name.to_lowercase(),
+            name: name.to_string(),
+            url: None,
+            nationality: None,
+        }
+    }
+
+    fn driver(family_name: &str) -> Driver {
+        Driver {
+            id: family_name.to_lowercase(),
+            code: None,
+            given_name: String::new(),
+            family_name: family_name.to_string(),
+            url: None,
+            nationality: None,
+            date_of_birth: None,
+        }
+    }
+
+    fn records(family_name: &str, finishes: &[(&str, &str, &str, &str)]) -> DriverRecords {
+        DriverRecords {
+            driver: driver(family_name),
+            records: finishes
+                .iter()
+                .map(|(round, position, points, name)| Record {
+                    round: round.to_string().try_into().unwrap(),
+                    position: position.to_string().try_into().unwrap(),
+                    points: points.to_string().try_into().unwrap(),
+                    sprint_points: Points::default(),
+                    team: Some(team(name)),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn compares_teammates() {
+        let drivers = [
+            records(
+                "Perez",
+                &[("1", "5", "10", "Red Bull"), ("2", "11", "0", "Red Bull")],
+            ),
+            records(
+                "Verstappen",
+                &[("1", "2", "18", "Red Bull"), ("2", "1", "25", "Red Bull")],
+            ),
+            records(
+                "Hamilton",
+                &[("1", "1", "25", "Mercedes"), ("2", "2", "19", "Mercedes")],
+            ),
+            records(
+                "Bottas",
+                &[("1", "3", "15", "Mercedes"), ("2", "19", "0", "Mercedes")],
+            ),
+        ];
+
+        let battles = teammate_battles(&drivers);
+
+        assert_eq!(battles.len(), 2);
+        let mercedes = &battles[0];
+        assert_eq!(mercedes.team.name, "Mercedes");
+        assert_eq!(mercedes.drivers[0].family_name, "Hamilton");
+        assert_eq!(mercedes.points, [Points::new(44.0), Points::new(15.0)]);
+        assert_eq!(mercedes.ahead, [2, 0]);
+        let red_bull = &battles[1];
+        assert_eq!(red_bull.drivers[0].family_name, "Verstappen");
+        assert_eq!(red_bull.ahead, [2, 0]);
+        assert_eq!(
+            red_bull.cumulative.last().unwrap().1,
+            [Points::new(43.0), Points::new(10.0)]
+        );
+    }
+
+    #[test]
+    fn counts_only_rounds_for_the_team() {
+        // Albon replaced Gasly from round 2
+        let drivers = [
+            records(
+                "Verstappen",
+                &[
+                    ("1", "1", "25", "Red Bull"),
+                    ("2", "3", "15", "Red Bull"),
+                    ("3", "2", "18", "Red Bull"),
+                ],
+            ),
+            records(
+                "Gasly",
+                &[("1", "4", "12", "Red Bull"), ("2", "8", "4", "Toro Rosso")],
+            ),
+            records(
+                "Albon",
+                &[("2", "2", "18", "Red Bull"), ("3", "5", "10", "Red Bull")],
+            ),
+        ];
+
+        let battles = teammate_battles(&drivers);
+
+        assert_eq!(battles.len(), 2);
+        let gasly = battles
+            .iter()
+            .find(|battle| battle.drivers[1].family_name == "Gasly")
+            .unwrap();
+        assert_eq!(gasly.points, [Points::new(58.0), Points::new(12.0)]);
+        assert_eq!(gasly.shared_rounds(), 1);
+        let albon = battles
+            .iter()
+            .find(|battle| battle.drivers[1].family_name == "Albon")
+            .unwrap();
+        assert_eq!(albon.ahead, [1, 1]);
+        let rounds = albon
+            .cumulative
+            .iter()
+            .map(|(round, _)| round.get())
+            .collect::<Vec<u32>>();
+        assert_eq!(rounds, [1, 2, 3]);
+    }
+}
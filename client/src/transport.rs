@@ -0,0 +1,153 @@
+//! How requests reach the server. [`HttpTransport`] talks to a live backend,
+//! [`FixtureTransport`] answers from canned responses so the client and the
+//! frontends can be exercised offline.
+use graphql_client::QueryBody;
+use serde_json::Value;
+use std::{future::Future, pin::Pin};
+
+use crate::Error;
+
+/// A request with its variables already serialized.
+pub type Request = QueryBody<Value>;
+
+// browser futures can't be sent between threads
+#[cfg(not(target_arch = "wasm32"))]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+#[cfg(target_arch = "wasm32")]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
+pub trait Transport: Send + Sync {
+    /// Sends `request` and returns the raw response body, `{"data": ...}`.
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Value, Error>>;
+}
+
+pub struct HttpTransport {
+    http: reqwest::Client,
+    gql_addr: String,
+}
+
+impl HttpTransport {
+    /// `gql_addr` is the server root, queries are posted to `{gql_addr}/query`.
+    pub fn new(gql_addr: impl Into<String>) -> Self {
+        HttpTransport {
+            http: reqwest::Client::new(),
+            gql_addr: gql_addr.into(),
+        }
+    }
+}
+
+impl Transport for HttpTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Value, Error>> {
+        Box::pin(async move {
+            Ok(self
+                .http
+                .post(format!("{}/query", self.gql_addr))
+                .json(&request)
+                .send()
+                .await?
+                .json()
+                .await?)
+        })
+    }
+}
+
+struct Fixture {
+    operation: String,
+    variables: Value,
+    response: Value,
+}
+
+/// Canned responses keyed by operation name and variables.
+#[derive(Default)]
+pub struct FixtureTransport {
+    fixtures: Vec<Fixture>,
+}
+
+impl FixtureTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answers `operation` called with exactly `variables` with `response`.
+    pub fn with(mut self, operation: &str, variables: Value, response: Value) -> Self {
+        self.fixtures.push(Fixture {
+            operation: operation.to_string(),
+            variables,
+            response,
+        });
+        self
+    }
+}
+
+impl Transport for FixtureTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Value, Error>> {
+        let response = self
+            .fixtures
+            .iter()
+            .find(|fixture| {
+                fixture.operation == request.operation_name
+                    && fixture.variables == request.variables
+            })
+            .map(|fixture| fixture.response.clone())
+            .ok_or_else(|| {
+                Error::Transport(format!(
+                    "no fixture for {} with {}",
+                    request.operation_name, request.variables
+                ))
+            });
+        Box::pin(std::future::ready(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::Client;
+
+    fn client() -> Client {
+        Client::with_transport(FixtureTransport::new().with(
+            "Constructors",
+            json!({ "year": "2021", "round": null }),
+            json!({
+                "data": {
+                    "ConstructorStandings": {
+                        "teams": [
+                            {
+                                "points": "613.5",
+                                "position": "1",
+                                "wins": "9",
+                                "team": { "id": "mercedes", "name": "Mercedes", "url": null }
+                            },
+                            null
+                        ]
+                    }
+                }
+            }),
+        ))
+    }
+
+    #[tokio::test]
+    async fn answers_matching_fixture() {
+        let standings = client().constructor_standings("2021").await.unwrap();
+
+        assert_eq!(standings.len(), 1);
+        assert_eq!(standings[0].team.name, "Mercedes");
+        assert_eq!(standings[0].points.get(), 613.5);
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_variables() {
+        let err = client().constructor_standings("2020").await.unwrap_err();
+
+        assert!(matches!(err, Error::Transport(_)));
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_operation() {
+        let err = client().driver_standings("2021").await.unwrap_err();
+
+        assert!(matches!(err, Error::Transport(_)));
+    }
+}
@@ -6,6 +6,7 @@ import (
 	"bytes"
 	"context"
 	"errors"
+	"io"
 	"strconv"
 	"sync"
 
@@ -35,6 +36,7 @@ type Config struct {
 
 type ResolverRoot interface {
 	Query() QueryResolver
+	Subscription() SubscriptionResolver
 }
 
 type DirectiveRoot struct {
@@ -125,6 +127,7 @@ type ComplexityRoot struct {
 	}
 
 	Query struct {
+		CircuitRaces           func(childComplexity int, id string) int
 		Circuits               func(childComplexity int, year *string) int
 		ConstructorStandings   func(childComplexity int, filter *model.StandingsFilter) int
 		DriverStandings        func(childComplexity int, filter *model.StandingsFilter) int
@@ -143,9 +146,11 @@ type ComplexityRoot struct {
 	}
 
 	Record struct {
-		Points   func(childComplexity int) int
-		Position func(childComplexity int) int
-		Round    func(childComplexity int) int
+		Constructor  func(childComplexity int) int
+		Points       func(childComplexity int) int
+		Position     func(childComplexity int) int
+		Round        func(childComplexity int) int
+		SprintPoints func(childComplexity int) int
 	}
 
 	ScheduleReport struct {
@@ -153,6 +158,10 @@ type ComplexityRoot struct {
 		Season func(childComplexity int) int
 	}
 
+	Subscription struct {
+		StandingsUpdated func(childComplexity int, year *string) int
+	}
+
 	TeamStanding struct {
 		Points   func(childComplexity int) int
 		Position func(childComplexity int) int
@@ -173,8 +182,12 @@ type QueryResolver interface {
 	DriversSeasonalRecords(ctx context.Context, filter *model.StandingsFilter) (*model.DriverGraphReport, error)
 	Circuits(ctx context.Context, year *string) (*model.CircuitsReport, error)
 	Schedule(ctx context.Context, year *string) (*model.ScheduleReport, error)
+	CircuitRaces(ctx context.Context, id string) (*model.ScheduleReport, error)
 	LapTimes(ctx context.Context, filter *model.LapTimesFilter) (*model.LapTimesReport, error)
 }
+type SubscriptionResolver interface {
+	StandingsUpdated(ctx context.Context, year *string) (<-chan *model.DriverStandingsReport, error)
+}
 
 type executableSchema struct {
 	resolvers  ResolverRoot
@@ -520,6 +533,18 @@ func (e *executableSchema) Complexity(typeName, field string, childComplexity in
 
 		return e.complexity.Location.Long(childComplexity), true
 
+	case "Query.CircuitRaces":
+		if e.complexity.Query.CircuitRaces == nil {
+			break
+		}
+
+		args, err := ec.field_Query_CircuitRaces_args(context.TODO(), rawArgs)
+		if err != nil {
+			return 0, false
+		}
+
+		return e.complexity.Query.CircuitRaces(childComplexity, args["id"].(string)), true
+
 	case "Query.Circuits":
 		if e.complexity.Query.Circuits == nil {
 			break
@@ -634,6 +659,13 @@ func (e *executableSchema) Complexity(typeName, field string, childComplexity in
 
 		return e.complexity.Race.URL(childComplexity), true
 
+	case "Record.constructor":
+		if e.complexity.Record.Constructor == nil {
+			break
+		}
+
+		return e.complexity.Record.Constructor(childComplexity), true
+
 	case "Record.points":
 		if e.complexity.Record.Points == nil {
 			break
@@ -655,6 +687,13 @@ func (e *executableSchema) Complexity(typeName, field string, childComplexity in
 
 		return e.complexity.Record.Round(childComplexity), true
 
+	case "Record.sprintPoints":
+		if e.complexity.Record.SprintPoints == nil {
+			break
+		}
+
+		return e.complexity.Record.SprintPoints(childComplexity), true
+
 	case "ScheduleReport.races":
 		if e.complexity.ScheduleReport.Races == nil {
 			break
@@ -669,6 +708,18 @@ func (e *executableSchema) Complexity(typeName, field string, childComplexity in
 
 		return e.complexity.ScheduleReport.Season(childComplexity), true
 
+	case "Subscription.StandingsUpdated":
+		if e.complexity.Subscription.StandingsUpdated == nil {
+			break
+		}
+
+		args, err := ec.field_Subscription_StandingsUpdated_args(context.TODO(), rawArgs)
+		if err != nil {
+			return 0, false
+		}
+
+		return e.complexity.Subscription.StandingsUpdated(childComplexity, args["year"].(*string)), true
+
 	case "TeamStanding.points":
 		if e.complexity.TeamStanding.Points == nil {
 			break
@@ -738,6 +789,23 @@ func (e *executableSchema) Exec(ctx context.Context) graphql.ResponseHandler {
 			var buf bytes.Buffer
 			data.MarshalGQL(&buf)
 
+			return &graphql.Response{
+				Data: buf.Bytes(),
+			}
+		}
+	case ast.Subscription:
+		next := ec._Subscription(ctx, rc.Operation.SelectionSet)
+
+		var buf bytes.Buffer
+		return func(ctx context.Context) *graphql.Response {
+			buf.Reset()
+			data := next()
+
+			if data == nil {
+				return nil
+			}
+			data.MarshalGQL(&buf)
+
 			return &graphql.Response{
 				Data: buf.Bytes(),
 			}
@@ -826,6 +894,8 @@ type Record {
   round: String
   position: String
   points: String
+  sprintPoints: String
+  constructor: Constructor
 }
 
 type CircuitsReport {
@@ -902,8 +972,13 @@ type Query {
   DriversSeasonalRecords(filter: StandingsFilter = {year: "current", top: -1}): DriverGraphReport
   Circuits(year: String = current): CircuitsReport
   Schedule(year: String = current): ScheduleReport
+  CircuitRaces(id: String!): ScheduleReport
   LapTimes(filter: LapTimesFilter = {year: "current", round: "1", lap: "1"}): LapTimesReport
 }
+
+type Subscription {
+  StandingsUpdated(year: String = current): DriverStandingsReport
+}
 `, BuiltIn: false},
 }
 var parsedSchema = gqlparser.MustLoadSchema(sources...)
@@ -912,6 +987,21 @@ var parsedSchema = gqlparser.MustLoadSchema(sources...)
 
 // region    ***************************** args.gotpl *****************************
 
+func (ec *executionContext) field_Query_CircuitRaces_args(ctx context.Context, rawArgs map[string]interface{}) (map[string]interface{}, error) {
+	var err error
+	args := map[string]interface{}{}
+	var arg0 string
+	if tmp, ok := rawArgs["id"]; ok {
+		ctx := graphql.WithPathContext(ctx, graphql.NewPathWithField("id"))
+		arg0, err = ec.unmarshalNString2string(ctx, tmp)
+		if err != nil {
+			return nil, err
+		}
+	}
+	args["id"] = arg0
+	return args, nil
+}
+
 func (ec *executionContext) field_Query_Circuits_args(ctx context.Context, rawArgs map[string]interface{}) (map[string]interface{}, error) {
 	var err error
 	args := map[string]interface{}{}
@@ -1017,6 +1107,21 @@ func (ec *executionContext) field_Query___type_args(ctx context.Context, rawArgs
 	return args, nil
 }
 
+func (ec *executionContext) field_Subscription_StandingsUpdated_args(ctx context.Context, rawArgs map[string]interface{}) (map[string]interface{}, error) {
+	var err error
+	args := map[string]interface{}{}
+	var arg0 *string
+	if tmp, ok := rawArgs["year"]; ok {
+		ctx := graphql.WithPathContext(ctx, graphql.NewPathWithField("year"))
+		arg0, err = ec.unmarshalOString2ᚖstring(ctx, tmp)
+		if err != nil {
+			return nil, err
+		}
+	}
+	args["year"] = arg0
+	return args, nil
+}
+
 func (ec *executionContext) field___Type_enumValues_args(ctx context.Context, rawArgs map[string]interface{}) (map[string]interface{}, error) {
 	var err error
 	args := map[string]interface{}{}
@@ -2754,6 +2859,45 @@ func (ec *executionContext) _Query_Schedule(ctx context.Context, field graphql.C
 	return ec.marshalOScheduleReport2ᚖgithubᚗcomᚋalexanderjosephᚋformula1ᚋformulagraphqlᚋgraphᚋmodelᚐScheduleReport(ctx, field.Selections, res)
 }
 
+func (ec *executionContext) _Query_CircuitRaces(ctx context.Context, field graphql.CollectedField) (ret graphql.Marshaler) {
+	defer func() {
+		if r := recover(); r != nil {
+			ec.Error(ctx, ec.Recover(ctx, r))
+			ret = graphql.Null
+		}
+	}()
+	fc := &graphql.FieldContext{
+		Object:     "Query",
+		Field:      field,
+		Args:       nil,
+		IsMethod:   true,
+		IsResolver: true,
+	}
+
+	ctx = graphql.WithFieldContext(ctx, fc)
+	rawArgs := field.ArgumentMap(ec.Variables)
+	args, err := ec.field_Query_CircuitRaces_args(ctx, rawArgs)
+	if err != nil {
+		ec.Error(ctx, err)
+		return graphql.Null
+	}
+	fc.Args = args
+	resTmp, err := ec.ResolverMiddleware(ctx, func(rctx context.Context) (interface{}, error) {
+		ctx = rctx // use context from middleware stack in children
+		return ec.resolvers.Query().CircuitRaces(rctx, args["id"].(string))
+	})
+	if err != nil {
+		ec.Error(ctx, err)
+		return graphql.Null
+	}
+	if resTmp == nil {
+		return graphql.Null
+	}
+	res := resTmp.(*model.ScheduleReport)
+	fc.Result = res
+	return ec.marshalOScheduleReport2ᚖgithubᚗcomᚋalexanderjosephᚋformula1ᚋformulagraphqlᚋgraphᚋmodelᚐScheduleReport(ctx, field.Selections, res)
+}
+
 func (ec *executionContext) _Query_LapTimes(ctx context.Context, field graphql.CollectedField) (ret graphql.Marshaler) {
 	defer func() {
 		if r := recover(); r != nil {
@@ -3152,6 +3296,70 @@ func (ec *executionContext) _Record_points(ctx context.Context, field graphql.Co
 	return ec.marshalOString2ᚖstring(ctx, field.Selections, res)
 }
 
+func (ec *executionContext) _Record_sprintPoints(ctx context.Context, field graphql.CollectedField, obj *model.Record) (ret graphql.Marshaler) {
+	defer func() {
+		if r := recover(); r != nil {
+			ec.Error(ctx, ec.Recover(ctx, r))
+			ret = graphql.Null
+		}
+	}()
+	fc := &graphql.FieldContext{
+		Object:     "Record",
+		Field:      field,
+		Args:       nil,
+		IsMethod:   false,
+		IsResolver: false,
+	}
+
+	ctx = graphql.WithFieldContext(ctx, fc)
+	resTmp, err := ec.ResolverMiddleware(ctx, func(rctx context.Context) (interface{}, error) {
+		ctx = rctx // use context from middleware stack in children
+		return obj.SprintPoints, nil
+	})
+	if err != nil {
+		ec.Error(ctx, err)
+		return graphql.Null
+	}
+	if resTmp == nil {
+		return graphql.Null
+	}
+	res := resTmp.(*string)
+	fc.Result = res
+	return ec.marshalOString2ᚖstring(ctx, field.Selections, res)
+}
+
+func (ec *executionContext) _Record_constructor(ctx context.Context, field graphql.CollectedField, obj *model.Record) (ret graphql.Marshaler) {
+	defer func() {
+		if r := recover(); r != nil {
+			ec.Error(ctx, ec.Recover(ctx, r))
+			ret = graphql.Null
+		}
+	}()
+	fc := &graphql.FieldContext{
+		Object:     "Record",
+		Field:      field,
+		Args:       nil,
+		IsMethod:   false,
+		IsResolver: false,
+	}
+
+	ctx = graphql.WithFieldContext(ctx, fc)
+	resTmp, err := ec.ResolverMiddleware(ctx, func(rctx context.Context) (interface{}, error) {
+		ctx = rctx // use context from middleware stack in children
+		return obj.Constructor, nil
+	})
+	if err != nil {
+		ec.Error(ctx, err)
+		return graphql.Null
+	}
+	if resTmp == nil {
+		return graphql.Null
+	}
+	res := resTmp.(*model.Constructor)
+	fc.Result = res
+	return ec.marshalOConstructor2ᚖgithubᚗcomᚋalexanderjosephᚋformula1ᚋformulagraphqlᚋgraphᚋmodelᚐConstructor(ctx, field.Selections, res)
+}
+
 func (ec *executionContext) _ScheduleReport_season(ctx context.Context, field graphql.CollectedField, obj *model.ScheduleReport) (ret graphql.Marshaler) {
 	defer func() {
 		if r := recover(); r != nil {
@@ -3216,6 +3424,55 @@ func (ec *executionContext) _ScheduleReport_races(ctx context.Context, field gra
 	return ec.marshalORace2ᚕᚖgithubᚗcomᚋalexanderjosephᚋformula1ᚋformulagraphqlᚋgraphᚋmodelᚐRace(ctx, field.Selections, res)
 }
 
+func (ec *executionContext) _Subscription_StandingsUpdated(ctx context.Context, field graphql.CollectedField) (ret func() graphql.Marshaler) {
+	defer func() {
+		if r := recover(); r != nil {
+			ec.Error(ctx, ec.Recover(ctx, r))
+			ret = nil
+		}
+	}()
+	fc := &graphql.FieldContext{
+		Object:     "Subscription",
+		Field:      field,
+		Args:       nil,
+		IsMethod:   true,
+		IsResolver: true,
+	}
+
+	ctx = graphql.WithFieldContext(ctx, fc)
+	rawArgs := field.ArgumentMap(ec.Variables)
+	args, err := ec.field_Subscription_StandingsUpdated_args(ctx, rawArgs)
+	if err != nil {
+		ec.Error(ctx, err)
+		return nil
+	}
+	fc.Args = args
+	resTmp, err := ec.ResolverMiddleware(ctx, func(rctx context.Context) (interface{}, error) {
+		ctx = rctx // use context from middleware stack in children
+		return ec.resolvers.Subscription().StandingsUpdated(rctx, args["year"].(*string))
+	})
+	if err != nil {
+		ec.Error(ctx, err)
+		return nil
+	}
+	if resTmp == nil {
+		return nil
+	}
+	return func() graphql.Marshaler {
+		res, ok := <-resTmp.(<-chan *model.DriverStandingsReport)
+		if !ok {
+			return nil
+		}
+		return graphql.WriterFunc(func(w io.Writer) {
+			w.Write([]byte{'{'})
+			graphql.MarshalString(field.Alias).MarshalGQL(w)
+			w.Write([]byte{':'})
+			ec.marshalODriverStandingsReport2ᚖgithubᚗcomᚋalexanderjosephᚋformula1ᚋformulagraphqlᚋgraphᚋmodelᚐDriverStandingsReport(ctx, field.Selections, res).MarshalGQL(w)
+			w.Write([]byte{'}'})
+		})
+	}
+}
+
 func (ec *executionContext) _TeamStanding_position(ctx context.Context, field graphql.CollectedField, obj *model.TeamStanding) (ret graphql.Marshaler) {
 	defer func() {
 		if r := recover(); r != nil {
@@ -5426,6 +5683,26 @@ func (ec *executionContext) _Query(ctx context.Context, sel ast.SelectionSet) gr
 				return ec.OperationContext.RootResolverMiddleware(ctx, innerFunc)
 			}
 
+			out.Concurrently(i, func() graphql.Marshaler {
+				return rrm(innerCtx)
+			})
+		case "CircuitRaces":
+			field := field
+
+			innerFunc := func(ctx context.Context) (res graphql.Marshaler) {
+				defer func() {
+					if r := recover(); r != nil {
+						ec.Error(ctx, ec.Recover(ctx, r))
+					}
+				}()
+				res = ec._Query_CircuitRaces(ctx, field)
+				return res
+			}
+
+			rrm := func(ctx context.Context) graphql.Marshaler {
+				return ec.OperationContext.RootResolverMiddleware(ctx, innerFunc)
+			}
+
 			out.Concurrently(i, func() graphql.Marshaler {
 				return rrm(innerCtx)
 			})
@@ -5568,6 +5845,20 @@ func (ec *executionContext) _Record(ctx context.Context, sel ast.SelectionSet, o
 
 			out.Values[i] = innerFunc(ctx)
 
+		case "sprintPoints":
+			innerFunc := func(ctx context.Context) (res graphql.Marshaler) {
+				return ec._Record_sprintPoints(ctx, field, obj)
+			}
+
+			out.Values[i] = innerFunc(ctx)
+
+		case "constructor":
+			innerFunc := func(ctx context.Context) (res graphql.Marshaler) {
+				return ec._Record_constructor(ctx, field, obj)
+			}
+
+			out.Values[i] = innerFunc(ctx)
+
 		default:
 			panic("unknown field " + strconv.Quote(field.Name))
 		}
@@ -5614,6 +5905,26 @@ func (ec *executionContext) _ScheduleReport(ctx context.Context, sel ast.Selecti
 	return out
 }
 
+var subscriptionImplementors = []string{"Subscription"}
+
+func (ec *executionContext) _Subscription(ctx context.Context, sel ast.SelectionSet) func() graphql.Marshaler {
+	fields := graphql.CollectFields(ec.OperationContext, sel, subscriptionImplementors)
+	ctx = graphql.WithFieldContext(ctx, &graphql.FieldContext{
+		Object: "Subscription",
+	})
+	if len(fields) != 1 {
+		ec.Errorf(ctx, "must subscribe to exactly one stream")
+		return nil
+	}
+
+	switch fields[0].Name {
+	case "StandingsUpdated":
+		return ec._Subscription_StandingsUpdated(ctx, fields[0])
+	default:
+		panic("unknown field " + strconv.Quote(fields[0].Name))
+	}
+}
+
 var teamStandingImplementors = []string{"TeamStanding"}
 
 func (ec *executionContext) _TeamStanding(ctx context.Context, sel ast.SelectionSet, obj *model.TeamStanding) graphql.Marshaler {
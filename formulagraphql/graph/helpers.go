@@ -2,6 +2,7 @@ package graph
 
 import (
 	"encoding/json"
+	"fmt"
 	"net/http"
 
 	"github.com/alexanderjoseph/formula1/formulagraphql/graph/model"
@@ -9,6 +10,7 @@ import (
 	"github.com/alexanderjoseph/formula1/formulagraphql/models/constructors"
 	"github.com/alexanderjoseph/formula1/formulagraphql/models/drivers"
 	"github.com/alexanderjoseph/formula1/formulagraphql/models/race"
+	"github.com/alexanderjoseph/formula1/formulagraphql/models/raceresults"
 )
 
 func getTeams(in []constructors.ConstructorStandings, limit int) []*model.TeamStanding {
@@ -139,6 +141,28 @@ func getRaces(in []race.Race) []*model.Race {
 	return ret
 }
 
+// getCircuitRaces is getRaces for races held at the same circuit, which only
+// needs its image looked up once.
+func getCircuitRaces(in []race.Race) []*model.Race {
+	ret := []*model.Race{}
+	if len(in) == 0 {
+		return ret
+	}
+	circuit := getCircuit(in[0].Circuit)
+	for i := range in {
+		race := in[i]
+		ret = append(ret, &model.Race{
+			Round:    &race.Round,
+			URL:      &race.URL,
+			RaceName: &race.RaceName,
+			Date:     &race.Date,
+			Time:     &race.Time,
+			Circuit:  circuit,
+		})
+	}
+	return ret
+}
+
 func getLapTimes(in []race.Race) []*model.Lap {
 	ret := []*model.Lap{}
 	for i := range in {
@@ -169,3 +193,32 @@ func getTimings(in race.Laps) []*model.Timing {
 	}
 	return ret
 }
+
+// getSprintPoints maps each driver to the points they scored in the sprint of
+// a round, it's empty for rounds without a sprint.
+func (r *Resolver) getSprintPoints(year, round string) (map[string]*string, error) {
+	resp, err := r.client.Get(fmt.Sprintf("%s/%s/%s/sprint.json", r.baseURL, year, round))
+	if err != nil {
+		return nil, fmt.Errorf("getting sprint results from ergast: %w", err)
+	}
+	defer resp.Body.Close()
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("unexpected status code: %d", resp.StatusCode)
+	}
+
+	var rr raceresults.Resp
+	err = json.NewDecoder(resp.Body).Decode(&rr)
+	if err != nil {
+		return nil, fmt.Errorf("decoding response: %w", err)
+	}
+
+	points := make(map[string]*string)
+	for _, race := range rr.MRData.RaceTable.Races {
+		for i := range race.SprintResults {
+			result := race.SprintResults[i]
+			points[result.Driver.DriverID] = &result.Points
+		}
+	}
+	return points, nil
+}
@@ -101,9 +101,11 @@ type Race struct {
 }
 
 type Record struct {
-	Round    *string `json:"round"`
-	Position *string `json:"position"`
-	Points   *string `json:"points"`
+	Round        *string      `json:"round"`
+	Position     *string      `json:"position"`
+	Points       *string      `json:"points"`
+	SprintPoints *string      `json:"sprintPoints"`
+	Constructor  *Constructor `json:"constructor"`
 }
 
 type ScheduleReport struct {
@@ -94,31 +94,41 @@ func (r *queryResolver) DriverStandings(ctx context.Context, filter *model.Stand
 }
 
 func (r *queryResolver) DriversSeasonalRecords(ctx context.Context, filter *model.StandingsFilter) (*model.DriverGraphReport, error) {
-	// get the schedule for the season
-	resp, err := r.client.Get(fmt.Sprintf("%s/%s.json", r.baseURL, *filter.Year))
-	if err != nil {
-		return nil, fmt.Errorf("getting schedule from ergast: %w", err)
-	}
+	var rounds []string
+	if filter.Round != nil {
+		// a single round needs only its own results
+		rounds = []string{*filter.Round}
+	} else {
+		// get the schedule for the season
+		resp, err := r.client.Get(fmt.Sprintf("%s/%s.json", r.baseURL, *filter.Year))
+		if err != nil {
+			return nil, fmt.Errorf("getting schedule from ergast: %w", err)
+		}
 
-	if resp.StatusCode != http.StatusOK {
-		return nil, fmt.Errorf("unexpected status code: %d", resp.StatusCode)
-	}
+		if resp.StatusCode != http.StatusOK {
+			return nil, fmt.Errorf("unexpected status code: %d", resp.StatusCode)
+		}
 
-	var cr race.Resp
-	err = json.NewDecoder(resp.Body).Decode(&cr)
-	if err != nil {
-		return nil, fmt.Errorf("decoding response: %w", err)
-	}
+		var cr race.Resp
+		err = json.NewDecoder(resp.Body).Decode(&cr)
+		if err != nil {
+			return nil, fmt.Errorf("decoding response: %w", err)
+		}
 
-	if len(cr.MRData.RaceTable.Races) == 0 {
-		return nil, fmt.Errorf("schedule not found")
+		if len(cr.MRData.RaceTable.Races) == 0 {
+			return nil, fmt.Errorf("schedule not found")
+		}
+
+		for _, race := range cr.MRData.RaceTable.Races {
+			rounds = append(rounds, race.Round)
+		}
 	}
 
 	drivers := make(map[string]*model.DriverGraph)
 
-	// iterate through ever race of a season and get the results for each driver
-	for _, race := range cr.MRData.RaceTable.Races {
-		resp, err := r.client.Get(fmt.Sprintf("%s/%s/%s/results.json", r.baseURL, *filter.Year, race.Round))
+	// iterate through every round asked for and get the results for each driver
+	for _, round := range rounds {
+		resp, err := r.client.Get(fmt.Sprintf("%s/%s/%s/results.json", r.baseURL, *filter.Year, round))
 		if err != nil {
 			return nil, fmt.Errorf("getting driver standings from ergast: %w", err)
 		}
@@ -133,6 +143,11 @@ func (r *queryResolver) DriversSeasonalRecords(ctx context.Context, filter *mode
 			return nil, fmt.Errorf("decoding response: %w", err)
 		}
 
+		sprintPoints, err := r.getSprintPoints(*filter.Year, round)
+		if err != nil {
+			return nil, err
+		}
+
 		if len(rr.MRData.RaceTable.Races) != 0 {
 			wg := sync.WaitGroup{}
 			for _, result := range rr.MRData.RaceTable.Races[0].Results {
@@ -145,9 +160,16 @@ func (r *queryResolver) DriversSeasonalRecords(ctx context.Context, filter *mode
 						}
 					}
 					drivers[result.Driver.DriverID].Records = append(drivers[result.Driver.DriverID].Records, &model.Record{
-						Round:    &round,
-						Points:   &result.Points,
-						Position: &result.Position,
+						Round:        &round,
+						Points:       &result.Points,
+						Position:     &result.Position,
+						SprintPoints: sprintPoints[result.Driver.DriverID],
+						Constructor: &model.Constructor{
+							ID:          &result.Constructor.ConstructorID,
+							Name:        &result.Constructor.Name,
+							URL:         &result.Constructor.URL,
+							Nationality: &result.Constructor.Nationality,
+						},
 					})
 					if drivers[result.Driver.DriverID].Driver == nil {
 						drivers[result.Driver.DriverID].Driver = &model.Driver{
@@ -161,7 +183,7 @@ func (r *queryResolver) DriversSeasonalRecords(ctx context.Context, filter *mode
 							Nationality: &result.Driver.Nationality,
 						}
 					}
-				}(race.Round, result)
+				}(round, result)
 			}
 			wg.Wait()
 		}
@@ -179,7 +201,12 @@ func (r *queryResolver) DriversSeasonalRecords(ctx context.Context, filter *mode
 }
 
 func (r *queryResolver) Circuits(ctx context.Context, year *string) (*model.CircuitsReport, error) {
-	resp, err := r.client.Get(fmt.Sprintf("%s/%s/circuits.json", r.baseURL, *year))
+	url := fmt.Sprintf("%s/%s/circuits.json", r.baseURL, *year)
+	if *year == "all" {
+		// ergast pages at 30 results, there have been fewer than 100 circuits
+		url = fmt.Sprintf("%s/circuits.json?limit=100", r.baseURL)
+	}
+	resp, err := r.client.Get(url)
 	if err != nil {
 		return nil, fmt.Errorf("getting circuits from ergast: %w", err)
 	}
@@ -238,6 +265,36 @@ func (r *queryResolver) Schedule(ctx context.Context, year *string) (*model.Sche
 	return ret, nil
 }
 
+func (r *queryResolver) CircuitRaces(ctx context.Context, id string) (*model.ScheduleReport, error) {
+	resp, err := r.client.Get(fmt.Sprintf("%s/circuits/%s/races.json?limit=1000", r.baseURL, id))
+	if err != nil {
+		return nil, fmt.Errorf("getting circuit races from ergast: %w", err)
+	}
+
+	if resp.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("unexpected status code: %d", resp.StatusCode)
+	}
+
+	var cr race.Resp
+	err = json.NewDecoder(resp.Body).Decode(&cr)
+	if err != nil {
+		return nil, fmt.Errorf("decoding response: %w", err)
+	}
+
+	if len(cr.MRData.RaceTable.Races) == 0 {
+		return nil, fmt.Errorf("circuit races not found")
+	}
+
+	races := getCircuitRaces(cr.MRData.RaceTable.Races)
+
+	ret := &model.ScheduleReport{
+		Season: &cr.MRData.RaceTable.Season,
+		Races:  races,
+	}
+
+	return ret, nil
+}
+
 func (r *queryResolver) LapTimes(ctx context.Context, filter *model.LapTimesFilter) (*model.LapTimesReport, error) {
 	resp, err := r.client.Get(fmt.Sprintf("%s/%s/%s/laps/%s.json", r.baseURL, *filter.Year, *filter.Round, *filter.Lap))
 	if err != nil {
@@ -268,7 +325,17 @@ func (r *queryResolver) LapTimes(ctx context.Context, filter *model.LapTimesFilt
 	return ret, nil
 }
 
+func (r *subscriptionResolver) StandingsUpdated(ctx context.Context, year *string) (<-chan *model.DriverStandingsReport, error) {
+	updates := make(chan *model.DriverStandingsReport, 1)
+	go r.watchStandings(ctx, year, updates)
+	return updates, nil
+}
+
 // Query returns generated.QueryResolver implementation.
 func (r *Resolver) Query() generated.QueryResolver { return &queryResolver{r} }
 
+// Subscription returns generated.SubscriptionResolver implementation.
+func (r *Resolver) Subscription() generated.SubscriptionResolver { return &subscriptionResolver{r} }
+
 type queryResolver struct{ *Resolver }
+type subscriptionResolver struct{ *Resolver }
@@ -0,0 +1,72 @@
+package graph
+
+import (
+	"context"
+	"log"
+	"strings"
+	"time"
+
+	"github.com/alexanderjoseph/formula1/formulagraphql/graph/model"
+)
+
+// standingsPollInterval is how often ergast is asked for the standings while
+// someone is subscribed, it has no way to push them.
+const standingsPollInterval = time.Minute
+
+// watchStandings sends the driver standings of year whenever they change,
+// until ctx is done. The standings at the time of subscribing aren't sent,
+// subscribers fetch those themselves.
+func (r *Resolver) watchStandings(ctx context.Context, year *string, updates chan<- *model.DriverStandingsReport) {
+	defer close(updates)
+
+	top := -1
+	filter := &model.StandingsFilter{Year: year, Top: &top}
+	ticker := time.NewTicker(standingsPollInterval)
+	defer ticker.Stop()
+
+	last := ""
+	for {
+		report, err := r.Query().DriverStandings(ctx, filter)
+		if err != nil {
+			log.Printf("polling driver standings: %v", err)
+		} else if key := standingsKey(report); key != last {
+			if last != "" {
+				select {
+				case updates <- report:
+				case <-ctx.Done():
+					return
+				}
+			}
+			last = key
+		}
+
+		select {
+		case <-ticker.C:
+		case <-ctx.Done():
+			return
+		}
+	}
+}
+
+// standingsKey tells two reports apart by the round and every driver's
+// points.
+func standingsKey(report *model.DriverStandingsReport) string {
+	var key strings.Builder
+	key.WriteString(value(report.Round))
+	for _, standing := range report.Drivers {
+		key.WriteString(";")
+		if standing.Driver != nil {
+			key.WriteString(value(standing.Driver.ID))
+		}
+		key.WriteString("=")
+		key.WriteString(value(standing.Points))
+	}
+	return key.String()
+}
+
+func value(s *string) string {
+	if s == nil {
+		return ""
+	}
+	return *s
+}
@@ -59,14 +59,15 @@ type Results struct {
 	FastestLap   FastestLap  `json:"FastestLap"`
 }
 type Races struct {
-	Season   string    `json:"season"`
-	Round    string    `json:"round"`
-	URL      string    `json:"url"`
-	RaceName string    `json:"raceName"`
-	Circuit  Circuit   `json:"Circuit"`
-	Date     string    `json:"date"`
-	Time     string    `json:"time"`
-	Results  []Results `json:"Results"`
+	Season        string    `json:"season"`
+	Round         string    `json:"round"`
+	URL           string    `json:"url"`
+	RaceName      string    `json:"raceName"`
+	Circuit       Circuit   `json:"Circuit"`
+	Date          string    `json:"date"`
+	Time          string    `json:"time"`
+	Results       []Results `json:"Results"`
+	SprintResults []Results `json:"SprintResults"`
 }
 type RaceTable struct {
 	Season string  `json:"season"`
@@ -0,0 +1,322 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::season::Season;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Tab {
+    Drivers,
+    Constructors,
+    Schedule,
+    Chart,
+}
+
+impl Tab {
+    pub const ALL: [Tab; 4] = [Tab::Drivers, Tab::Constructors, Tab::Schedule, Tab::Chart];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Tab::Drivers => "Drivers",
+            Tab::Constructors => "Constructors",
+            Tab::Schedule => "Schedule",
+            Tab::Chart => "Chart",
+        }
+    }
+
+    fn index(self) -> usize {
+        Tab::ALL
+            .iter()
+            .position(|tab| *tab == self)
+            .expect("every tab is listed")
+    }
+
+    fn next(self) -> Tab {
+        Tab::ALL[(self.index() + 1) % Tab::ALL.len()]
+    }
+
+    fn previous(self) -> Tab {
+        Tab::ALL[(self.index() + Tab::ALL.len() - 1) % Tab::ALL.len()]
+    }
+}
+
+pub enum Data {
+    Loading,
+    Loaded(Season),
+    Failed(String),
+}
+
+/// What the event loop should do after a key press.
+#[derive(PartialEq, Eq, Debug)]
+pub enum Action {
+    None,
+    Reload,
+    Quit,
+}
+
+pub struct App {
+    pub tab: Tab,
+    /// The season being requested, `current` or a year.
+    pub year: String,
+    /// Set while the user is typing a season.
+    pub input: Option<String>,
+    pub selected: usize,
+    pub data: Data,
+}
+
+impl App {
+    pub fn new(year: String) -> Self {
+        App {
+            tab: Tab::Drivers,
+            year,
+            input: None,
+            selected: 0,
+            data: Data::Loading,
+        }
+    }
+
+    pub fn tab_index(&self) -> usize {
+        self.tab.index()
+    }
+
+    fn rows(&self) -> usize {
+        match &self.data {
+            Data::Loaded(season) => match self.tab {
+                Tab::Drivers | Tab::Chart => season.drivers.len(),
+                Tab::Constructors => season.constructors.len(),
+                Tab::Schedule => season.races.len(),
+            },
+            _ => 0,
+        }
+    }
+
+    /// The season either side of the one shown, once it has loaded.
+    fn step_year(&self, step: i32) -> Option<String> {
+        let Data::Loaded(season) = &self.data else {
+            return None;
+        };
+        let year = season.year.parse::<i32>().ok()? + step;
+        Some(year.to_string())
+    }
+
+    fn select_year(&mut self, year: String) -> Action {
+        if year == self.year {
+            return Action::None;
+        }
+        self.year = year;
+        self.selected = 0;
+        self.data = Data::Loading;
+        Action::Reload
+    }
+
+    pub fn on_key(&mut self, key: KeyEvent) -> Action {
+        if let Some(input) = &mut self.input {
+            match key.code {
+                KeyCode::Char(c) if c.is_ascii_digit() && input.len() < 4 => input.push(c),
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Enter => {
+                    let year = self.input.take().unwrap_or_default();
+                    if !year.is_empty() {
+                        return self.select_year(year);
+                    }
+                }
+                KeyCode::Esc => self.input = None,
+                _ => {}
+            }
+            return Action::None;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Action::Quit,
+            KeyCode::Tab | KeyCode::Right | KeyCode::Char('l') => {
+                self.tab = self.tab.next();
+                self.selected = 0;
+            }
+            KeyCode::BackTab | KeyCode::Left | KeyCode::Char('h') => {
+                self.tab = self.tab.previous();
+                self.selected = 0;
+            }
+            KeyCode::Down | KeyCode::Char('j') if self.selected + 1 < self.rows() => {
+                self.selected += 1;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = self.selected.saturating_sub(1);
+            }
+            KeyCode::Home | KeyCode::Char('g') => self.selected = 0,
+            KeyCode::End | KeyCode::Char('G') => self.selected = self.rows().saturating_sub(1),
+            KeyCode::Char('[') => {
+                if let Some(year) = self.step_year(-1) {
+                    return self.select_year(year);
+                }
+            }
+            KeyCode::Char(']') => {
+                if let Some(year) = self.step_year(1) {
+                    return self.select_year(year);
+                }
+            }
+            KeyCode::Char('c') => return self.select_year("current".to_string()),
+            KeyCode::Char('s') | KeyCode::Char('/') => self.input = Some(String::new()),
+            KeyCode::Char('r') => {
+                self.data = Data::Loading;
+                return Action::Reload;
+            }
+            _ => {}
+        }
+        Action::None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyEvent;
+    use f1_client::{Circuit, Race};
+
+    fn press(app: &mut App, code: KeyCode) -> Action {
+        app.on_key(KeyEvent::from(code))
+    }
+
+    fn race(round: u32) -> Race {
+        Race {
+            round: round.to_string().try_into().unwrap(),
+            name: format!("Race {round}"),
+            url: None,
+            date: format!("2021-04-{:02}", round).try_into().unwrap(),
+            time: None,
+            circuit: Circuit {
+                id: String::new(),
+                name: String::new(),
+                url: None,
+                img: None,
+                location: None,
+            },
+        }
+    }
+
+    /// `year` loaded with a schedule of `rounds` races and nothing else.
+    fn loaded(year: &str, rounds: u32) -> App {
+        let mut app = App::new(year.to_string());
+        app.data = Data::Loaded(Season {
+            year: year.to_string(),
+            drivers: Vec::new(),
+            constructors: Vec::new(),
+            races: (1..=rounds).map(race).collect(),
+            series: Vec::new(),
+        });
+        app
+    }
+
+    #[test]
+    fn quits() {
+        let mut app = App::new("current".to_string());
+
+        assert_eq!(press(&mut app, KeyCode::Char('q')), Action::Quit);
+        assert_eq!(press(&mut app, KeyCode::Esc), Action::Quit);
+    }
+
+    #[test]
+    fn cycles_through_the_tabs() {
+        let mut app = loaded("2021", 3);
+        app.selected = 2;
+
+        assert_eq!(press(&mut app, KeyCode::Tab), Action::None);
+        assert_eq!(app.tab, Tab::Constructors);
+        assert_eq!(app.selected, 0);
+        press(&mut app, KeyCode::BackTab);
+        press(&mut app, KeyCode::Char('h'));
+        assert_eq!(app.tab, Tab::Chart);
+        press(&mut app, KeyCode::Right);
+        assert_eq!(app.tab, Tab::Drivers);
+    }
+
+    #[test]
+    fn keeps_the_selection_within_the_rows() {
+        let mut app = loaded("2021", 3);
+        app.tab = Tab::Schedule;
+
+        for _ in 0..5 {
+            press(&mut app, KeyCode::Char('j'));
+        }
+        assert_eq!(app.selected, 2);
+        press(&mut app, KeyCode::Up);
+        assert_eq!(app.selected, 1);
+        press(&mut app, KeyCode::Char('g'));
+        assert_eq!(app.selected, 0);
+        press(&mut app, KeyCode::Char('k'));
+        assert_eq!(app.selected, 0);
+        press(&mut app, KeyCode::End);
+        assert_eq!(app.selected, 2);
+        // the drivers tab has no rows in this season
+        press(&mut app, KeyCode::Tab);
+        press(&mut app, KeyCode::Down);
+        assert_eq!(app.selected, 0);
+    }
+
+    #[test]
+    fn steps_between_seasons_once_loaded() {
+        let mut app = App::new("current".to_string());
+        assert_eq!(press(&mut app, KeyCode::Char('[')), Action::None);
+
+        let mut app = loaded("2021", 3);
+        app.selected = 1;
+        assert_eq!(press(&mut app, KeyCode::Char('[')), Action::Reload);
+        assert_eq!(app.year, "2020");
+        assert_eq!(app.selected, 0);
+        assert!(matches!(app.data, Data::Loading));
+
+        let mut app = loaded("2021", 3);
+        assert_eq!(press(&mut app, KeyCode::Char(']')), Action::Reload);
+        assert_eq!(app.year, "2022");
+    }
+
+    #[test]
+    fn reloads_only_for_another_season() {
+        let mut app = App::new("current".to_string());
+        assert_eq!(press(&mut app, KeyCode::Char('c')), Action::None);
+
+        let mut app = loaded("2021", 3);
+        assert_eq!(press(&mut app, KeyCode::Char('c')), Action::Reload);
+        assert_eq!(app.year, "current");
+        assert_eq!(press(&mut app, KeyCode::Char('r')), Action::Reload);
+        assert!(matches!(app.data, Data::Loading));
+    }
+
+    #[test]
+    fn types_a_season() {
+        let mut app = loaded("2021", 3);
+
+        assert_eq!(press(&mut app, KeyCode::Char('s')), Action::None);
+        for c in "19x988".chars() {
+            assert_eq!(press(&mut app, KeyCode::Char(c)), Action::None);
+        }
+        // letters are ignored and a year is at most four digits
+        assert_eq!(app.input.as_deref(), Some("1998"));
+        press(&mut app, KeyCode::Backspace);
+        press(&mut app, KeyCode::Char('7'));
+        // keys that would otherwise quit or switch tabs are typed into the input
+        assert_eq!(press(&mut app, KeyCode::Char('q')), Action::None);
+        assert_eq!(press(&mut app, KeyCode::Tab), Action::None);
+        assert_eq!(app.tab, Tab::Drivers);
+
+        assert_eq!(press(&mut app, KeyCode::Enter), Action::Reload);
+        assert_eq!(app.year, "1997");
+        assert_eq!(app.input, None);
+    }
+
+    #[test]
+    fn cancels_typing_a_season() {
+        let mut app = loaded("2021", 3);
+
+        press(&mut app, KeyCode::Char('/'));
+        press(&mut app, KeyCode::Char('1'));
+        assert_eq!(press(&mut app, KeyCode::Esc), Action::None);
+        assert_eq!(app.input, None);
+        assert_eq!(app.year, "2021");
+
+        // nothing typed is nothing to load
+        press(&mut app, KeyCode::Char('s'));
+        assert_eq!(press(&mut app, KeyCode::Enter), Action::None);
+        assert_eq!(app.input, None);
+        assert_eq!(app.year, "2021");
+    }
+}
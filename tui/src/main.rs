@@ -0,0 +1,109 @@
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyEventKind},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use f1_client::Client;
+use ratatui::prelude::*;
+use std::{env, error::Error, io, panic, thread};
+use tokio::sync::mpsc;
+
+mod app;
+use app::{Action, App, Data};
+mod season;
+use season::Season;
+mod ui;
+
+type Term = Terminal<CrosstermBackend<io::Stdout>>;
+
+/// A season that finished loading, with the year it was requested as.
+type Loaded = (String, Result<Season, f1_client::Error>);
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let gql_addr = env::var("GQL_ADDR").unwrap_or_else(|_| "http://localhost:8080".to_string());
+    let year = env::args().nth(1).unwrap_or_else(|| "current".to_string());
+
+    // a panic would otherwise leave the shell in raw mode with its message
+    // drawn over by the alternate screen
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let _ = restore_terminal();
+        hook(info);
+    }));
+
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+
+    let result = run(&mut terminal, Client::new(gql_addr), App::new(year)).await;
+
+    restore_terminal()?;
+    result
+}
+
+fn restore_terminal() -> io::Result<()> {
+    disable_raw_mode()?;
+    execute!(io::stdout(), LeaveAlternateScreen, cursor::Show)
+}
+
+/// Reads terminal events on a thread of its own, as crossterm blocks, so
+/// keys are still handled while a season loads.
+fn read_events() -> mpsc::UnboundedReceiver<io::Result<Event>> {
+    let (sender, receiver) = mpsc::unbounded_channel();
+    thread::spawn(move || loop {
+        let event = event::read();
+        let failed = event.is_err();
+        if sender.send(event).is_err() || failed {
+            return;
+        }
+    });
+    receiver
+}
+
+fn load(client: &Client, year: &str, loaded: &mpsc::UnboundedSender<Loaded>) {
+    let (client, year, loaded) = (client.clone(), year.to_string(), loaded.clone());
+    tokio::spawn(async move {
+        let season = season::load(&client, &year).await;
+        let _ = loaded.send((year, season));
+    });
+}
+
+async fn run(terminal: &mut Term, client: Client, mut app: App) -> Result<(), Box<dyn Error>> {
+    let mut events = read_events();
+    let (loaded, mut seasons) = mpsc::unbounded_channel();
+    load(&client, &app.year, &loaded);
+
+    loop {
+        terminal.draw(|frame| ui::draw(frame, &app))?;
+        tokio::select! {
+            Some((year, season)) = seasons.recv() => {
+                // seasons switched away from before they loaded are dropped
+                if year == app.year {
+                    app.data = match season {
+                        Ok(season) => Data::Loaded(season),
+                        Err(err) => Data::Failed(err.to_string()),
+                    };
+                }
+            }
+            event = events.recv() => {
+                // the reader only stops after passing on an error
+                let Some(event) = event else {
+                    return Ok(());
+                };
+                let Event::Key(key) = event? else {
+                    continue;
+                };
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match app.on_key(key) {
+                    Action::Quit => return Ok(()),
+                    Action::Reload => load(&client, &app.year, &loaded),
+                    Action::None => {}
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use f1_client::{Client, ConstructorStanding, DriverRecords, DriverStanding, Error, Points, Race};
+
+pub struct Series {
+    pub code: String,
+    /// `(round, cumulative points)` pairs.
+    pub points: Vec<(f64, f64)>,
+}
+
+pub struct Season {
+    pub year: String,
+    pub drivers: Vec<DriverStanding>,
+    pub constructors: Vec<ConstructorStanding>,
+    pub races: Vec<Race>,
+    pub series: Vec<Series>,
+}
+
+pub async fn load(client: &Client, year: &str) -> Result<Season, Error> {
+    let (drivers, constructors, schedule, records) = tokio::try_join!(
+        client.driver_standings(year),
+        client.constructor_standings(year),
+        client.schedule(year),
+        client.driver_records(year),
+    )?;
+
+    Ok(Season {
+        year: schedule.season,
+        drivers,
+        constructors,
+        races: schedule.races,
+        series: records.into_iter().map(series).collect(),
+    })
+}
+
+fn series(driver: DriverRecords) -> Series {
+    let mut sum = Points::default();
+    let points = driver
+        .records
+        .into_iter()
+        .map(|record| {
+            sum = sum + record.points;
+            (f64::from(record.round.get()), sum.get())
+        })
+        .collect();
+    Series {
+        code: driver.driver.label().to_string(),
+        points,
+    }
+}
@@ -0,0 +1,227 @@
+use ratatui::{
+    prelude::*,
+    symbols::Marker,
+    widgets::{
+        Axis, Block, Borders, Cell, Chart, Dataset, Paragraph, Row, Table, TableState, Tabs,
+    },
+};
+
+use f1_client::Position;
+
+use crate::{
+    app::{App, Data, Tab},
+    season::Season,
+};
+
+const HELP: &str = "←/→ tab  ↑/↓ select  [/] season  s type season  c current  r reload  q quit";
+
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [header, body, footer] = areas(frame.size());
+
+    let title = match &app.input {
+        Some(input) => format!(" season: {input}_ "),
+        None => format!(" f1 {} ", app.year),
+    };
+    let tabs = Tabs::new(Tab::ALL.iter().map(|tab| tab.title()).collect())
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .select(app.tab_index())
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD | Modifier::REVERSED));
+    frame.render_widget(tabs, header);
+
+    match &app.data {
+        Data::Loading => frame.render_widget(Paragraph::new("loading"), body),
+        Data::Failed(error) => frame.render_widget(Paragraph::new(format!("error: {error}")), body),
+        Data::Loaded(season) => match app.tab {
+            Tab::Drivers => draw_drivers(frame, body, season, app.selected),
+            Tab::Constructors => draw_constructors(frame, body, season, app.selected),
+            Tab::Schedule => draw_schedule(frame, body, season, app.selected),
+            Tab::Chart => draw_chart(frame, body, season, app.selected),
+        },
+    }
+
+    frame.render_widget(
+        Paragraph::new(HELP).style(Style::default().fg(Color::DarkGray)),
+        footer,
+    );
+}
+
+fn areas(area: Rect) -> [Rect; 3] {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .split(area);
+    [chunks[0], chunks[1], chunks[2]]
+}
+
+fn podium(position: Position) -> Style {
+    match position.get() {
+        1 => Style::default().fg(Color::Yellow),
+        2 => Style::default().fg(Color::Gray),
+        3 => Style::default().fg(Color::LightRed),
+        _ => Style::default(),
+    }
+}
+
+fn draw_table<'a>(
+    frame: &mut Frame,
+    area: Rect,
+    header: Vec<&'a str>,
+    widths: &'a [Constraint],
+    rows: Vec<Row<'a>>,
+    selected: usize,
+) {
+    let table = Table::new(rows)
+        .header(Row::new(header).style(Style::default().add_modifier(Modifier::BOLD)))
+        .widths(widths)
+        .block(Block::default().borders(Borders::ALL))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    let mut state = TableState::default().with_selected(Some(selected));
+    frame.render_stateful_widget(table, area, &mut state);
+}
+
+fn draw_drivers(frame: &mut Frame, area: Rect, season: &Season, selected: usize) {
+    let rows = season
+        .drivers
+        .iter()
+        .map(|driver| {
+            Row::new(vec![
+                Cell::from(driver.position.to_string()).style(podium(driver.position)),
+                Cell::from(driver.driver.code.clone().unwrap_or_default()),
+                Cell::from(driver.driver.name()),
+                Cell::from(driver.points.to_string()),
+            ])
+        })
+        .collect();
+    draw_table(
+        frame,
+        area,
+        vec!["Pos", "Code", "Driver", "Points"],
+        &[
+            Constraint::Length(4),
+            Constraint::Length(5),
+            Constraint::Min(20),
+            Constraint::Length(7),
+        ],
+        rows,
+        selected,
+    );
+}
+
+fn draw_constructors(frame: &mut Frame, area: Rect, season: &Season, selected: usize) {
+    let rows = season
+        .constructors
+        .iter()
+        .map(|constructor| {
+            Row::new(vec![
+                Cell::from(constructor.position.to_string()).style(podium(constructor.position)),
+                Cell::from(constructor.team.name.as_str()),
+                Cell::from(constructor.points.to_string()),
+            ])
+        })
+        .collect();
+    draw_table(
+        frame,
+        area,
+        vec!["Pos", "Team", "Points"],
+        &[
+            Constraint::Length(4),
+            Constraint::Min(20),
+            Constraint::Length(7),
+        ],
+        rows,
+        selected,
+    );
+}
+
+fn draw_schedule(frame: &mut Frame, area: Rect, season: &Season, selected: usize) {
+    let rows = season
+        .races
+        .iter()
+        .map(|race| {
+            Row::new(vec![
+                Cell::from(race.round.to_string()),
+                Cell::from(race.name.as_str()),
+                Cell::from(race.date.to_string()),
+                Cell::from(race.circuit.name.as_str()),
+            ])
+        })
+        .collect();
+    draw_table(
+        frame,
+        area,
+        vec!["Round", "Name", "Date", "Circuit"],
+        &[
+            Constraint::Length(6),
+            Constraint::Min(20),
+            Constraint::Length(11),
+            Constraint::Min(20),
+        ],
+        rows,
+        selected,
+    );
+}
+
+/// Cumulative points per round, highlighting the driver selected in the
+/// standings order.
+fn draw_chart(frame: &mut Frame, area: Rect, season: &Season, selected: usize) {
+    let highlighted = season
+        .drivers
+        .get(selected)
+        .map(|standing| standing.driver.label());
+    let (max_round, max_points) = season
+        .series
+        .iter()
+        .flat_map(|series| series.points.iter())
+        .fold((1.0, 1.0), |(round, points): (f64, f64), (r, p)| {
+            (round.max(*r), points.max(*p))
+        });
+
+    // draw the highlighted driver last so it isn't hidden by the others
+    let mut series = season.series.iter().collect::<Vec<_>>();
+    series.sort_by_key(|series| Some(series.code.as_str()) == highlighted);
+    let datasets = series
+        .into_iter()
+        .map(|series| {
+            let style = if Some(series.code.as_str()) == highlighted {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            Dataset::default()
+                .name(series.code.as_str())
+                .marker(Marker::Dot)
+                .graph_type(ratatui::widgets::GraphType::Line)
+                .style(style)
+                .data(&series.points)
+        })
+        .collect();
+
+    let title = match highlighted {
+        Some(code) => format!(" cumulative points, {code} highlighted (↑/↓) "),
+        None => " cumulative points ".to_string(),
+    };
+    let chart = Chart::new(datasets)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .x_axis(
+            Axis::default()
+                .title("round")
+                .bounds([1.0, max_round])
+                .labels(vec!["1".into(), format!("{max_round}").into()]),
+        )
+        .y_axis(
+            Axis::default()
+                .title("points")
+                .bounds([0.0, max_points])
+                .labels(vec![
+                    "0".into(),
+                    format!("{}", (max_points / 2.0).round()).into(),
+                    format!("{max_points}").into(),
+                ]),
+        )
+        .hidden_legend_constraints((Constraint::Ratio(1, 1), Constraint::Ratio(1, 1)));
+    frame.render_widget(chart, area);
+}
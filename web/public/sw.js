@@ -0,0 +1,121 @@
+// Keeps the site usable offline. Registered by `src/offline.rs`.
+//
+// The app shell is fetched network first so a new build shows up as soon as
+// it's deployed, falling back to the cache. The last response to every
+// GraphQL query is kept too, and each page is told whether its data came from
+// the network or the cache so it can say how old the data is.
+
+// bump to drop everything cached by older workers
+const VERSION = 1;
+const SHELL_CACHE = `shell-v${VERSION}`;
+const DATA_CACHE = `graphql-v${VERSION}`;
+const SHELL = [
+  "./",
+  "manifest.webmanifest",
+  "icon.svg",
+  "tailwind.css",
+  "theme.css",
+  "a11y.css",
+  "assets/dioxus/web.js",
+  "assets/dioxus/web_bg.wasm",
+];
+const FETCHED_AT = "x-fetched-at";
+
+const scoped = (path) => new URL(path, self.registration.scope);
+
+self.addEventListener("install", (event) => {
+  event.waitUntil(
+    caches
+      .open(SHELL_CACHE)
+      .then((cache) =>
+        Promise.all(
+          SHELL.map((path) =>
+            cache.add(scoped(path)).catch(() => console.warn(`not caching ${path}`)),
+          ),
+        ),
+      )
+      .then(() => self.skipWaiting()),
+  );
+});
+
+self.addEventListener("activate", (event) => {
+  const current = [SHELL_CACHE, DATA_CACHE];
+  event.waitUntil(
+    caches
+      .keys()
+      .then((keys) =>
+        Promise.all(keys.filter((key) => !current.includes(key)).map((key) => caches.delete(key))),
+      )
+      .then(() => self.clients.claim()),
+  );
+});
+
+self.addEventListener("fetch", (event) => {
+  const { request } = event;
+  const url = new URL(request.url);
+  if (request.method === "POST" && url.pathname.endsWith("/query")) {
+    event.respondWith(graphql(event));
+  } else if (request.method === "GET" && url.origin === self.location.origin) {
+    event.respondWith(shell(request));
+  }
+});
+
+async function shell(request) {
+  const cache = await caches.open(SHELL_CACHE);
+  try {
+    const response = await fetch(request);
+    if (response.ok) {
+      await cache.put(request, response.clone());
+    }
+    return response;
+  } catch (error) {
+    const cached =
+      (await cache.match(request)) ??
+      // routes that weren't prerendered are all served by the app
+      (request.mode === "navigate" ? await cache.match(scoped("./")) : undefined);
+    if (cached) {
+      return cached;
+    }
+    throw error;
+  }
+}
+
+// POST requests can't be cache keys, so responses are kept under a URL made
+// from a digest of the query and its variables.
+async function graphql(event) {
+  const body = await event.request.clone().text();
+  const key = scoped(`graphql/${await digest(body)}`);
+  const cache = await caches.open(DATA_CACHE);
+  try {
+    const response = await fetch(event.request);
+    if (response.ok) {
+      const headers = new Headers(response.headers);
+      headers.set(FETCHED_AT, String(Date.now()));
+      const copy = new Response(await response.clone().blob(), {
+        status: response.status,
+        statusText: response.statusText,
+        headers,
+      });
+      await cache.put(key, copy);
+      report(event, { source: "network" });
+    }
+    return response;
+  } catch (error) {
+    const cached = await cache.match(key);
+    if (!cached) {
+      throw error;
+    }
+    report(event, { source: "cache", fetchedAt: Number(cached.headers.get(FETCHED_AT)) });
+    return cached;
+  }
+}
+
+async function digest(text) {
+  const hash = await crypto.subtle.digest("SHA-256", new TextEncoder().encode(text));
+  return Array.from(new Uint8Array(hash), (byte) => byte.toString(16).padStart(2, "0")).join("");
+}
+
+async function report(event, answer) {
+  const client = await self.clients.get(event.clientId);
+  client?.postMessage(JSON.stringify({ type: "graphql", ...answer }));
+}
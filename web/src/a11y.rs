@@ -0,0 +1,60 @@
+use dioxus::html::input_data::keyboard_types::Key;
+use dioxus::prelude::*;
+use f1_client::Position;
+use wasm_bindgen::JsCast;
+
+use crate::{
+    browser,
+    i18n::{self, Message},
+};
+
+pub const SR_ONLY: &str = "sr-only";
+
+pub fn row_id(table: &str, row: usize) -> String {
+    format!("{table}-row-{row}")
+}
+
+/// Moves focus between the rows of a table rendered with [`row_id`] ids,
+/// returning whether the key was handled.
+pub fn navigate_rows(key: &Key, table: &str, row: usize, rows: usize) -> bool {
+    let target = match key {
+        Key::ArrowDown if row + 1 < rows => row + 1,
+        Key::ArrowUp if row > 0 => row - 1,
+        Key::Home => 0,
+        Key::End if rows > 0 => rows - 1,
+        _ => return false,
+    };
+    let element = browser::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.get_element_by_id(&row_id(table, target)))
+        .and_then(|element| element.dyn_into::<web_sys::HtmlElement>().ok());
+    match element {
+        Some(element) => element.focus().is_ok(),
+        None => false,
+    }
+}
+
+#[inline_props]
+pub fn PodiumPosition(cx: Scope, position: Position) -> Element {
+    let locale = i18n::use_locale(cx);
+    let podium = match position.get() {
+        1 => Some(("🥇", Message::FirstPlace)),
+        2 => Some(("🥈", Message::SecondPlace)),
+        3 => Some(("🥉", Message::ThirdPlace)),
+        _ => None,
+    };
+
+    match podium {
+        Some((medal, message)) => render! {
+            span {
+                aria_hidden: "true",
+                "{medal}"
+            }
+            span {
+                class: SR_ONLY,
+                "{locale.t(message)}"
+            }
+        },
+        None => render! { "{position}" },
+    }
+}
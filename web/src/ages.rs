@@ -0,0 +1,103 @@
+use dioxus::prelude::*;
+use f1_client::{RaceAge, SeasonAges};
+
+use crate::{
+    flag::Flag,
+    i18n::{self, Message},
+    theme,
+};
+
+#[derive(PartialEq, Props)]
+pub struct ShowAgeStatsProps<'a> {
+    ages: &'a SeasonAges,
+}
+
+/// The youngest and oldest drivers to score and to win in a season, and the
+/// average age of the grid.
+pub fn ShowAgeStats<'a>(cx: Scope<'a, ShowAgeStatsProps<'a>>) -> Element {
+    let locale = i18n::use_locale(cx);
+    let ages = cx.props.ages;
+    let records = [
+        (Message::YoungestScorer, &ages.youngest_scorer),
+        (Message::OldestScorer, &ages.oldest_scorer),
+        (Message::YoungestWinner, &ages.youngest_winner),
+        (Message::OldestWinner, &ages.oldest_winner),
+    ];
+    let average_age = ages
+        .average_age
+        .map(|average| (average * 10.0).round() / 10.0);
+
+    cx.render(rsx! {
+        section {
+            aria_label: "{locale.t(Message::AgeStatistics)}",
+            padding: "10px",
+            background_color: theme::SURFACE,
+            h2 { "{locale.t(Message::AgeStatistics)}" }
+            dl {
+                for (label, record) in records {
+                    if let Some(record) = record {
+                        rsx! { AgeRecord { label: label, record: record } }
+                    }
+                }
+                if let Some(average) = average_age {
+                    rsx! {
+                        dt { "{locale.t(Message::AverageAge)}" }
+                        dd { "{locale.format_number(average)} {locale.t(Message::Years)}" }
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[derive(PartialEq, Props)]
+struct AgeRecordProps<'a> {
+    label: Message,
+    record: &'a RaceAge,
+}
+
+fn AgeRecord<'a>(cx: Scope<'a, AgeRecordProps<'a>>) -> Element {
+    let locale = i18n::use_locale(cx);
+    let record = cx.props.record;
+
+    cx.render(rsx! {
+        dt { "{locale.t(cx.props.label)}" }
+        dd {
+            Flag { country: record.driver.country() }
+            b { "{record.driver.name()}" }
+            " {locale.format_age(record.age)} · {record.race}"
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{i18n::Locale, testing};
+
+    fn Stats(cx: Scope) -> Element {
+        testing::use_fixtures(cx);
+        let client = crate::use_client(cx);
+        let ages = use_future(cx, (), |_| async move {
+            client.season_ages(testing::YEAR).await
+        });
+
+        match ages.value() {
+            Some(Ok(ages)) => render! { ShowAgeStats { ages: ages } },
+            _ => None,
+        }
+    }
+
+    #[tokio::test]
+    async fn renders_age_records() {
+        let html = testing::render(Stats).await;
+
+        assert!(!html.contains(Locale::En.t(Message::Loading)));
+        assert!(html.contains(Locale::En.t(Message::YoungestWinner)));
+        // Verstappen won at Imola aged 23
+        assert!(html.contains("Max Verstappen"));
+        assert!(html.contains("23 years, 200 days · Emilia Romagna Grand Prix"));
+        // Hamilton won in Bahrain and Portugal, the later win is the oldest
+        assert!(html.contains("36 years, 115 days · Portuguese Grand Prix"));
+    }
+}
@@ -0,0 +1,381 @@
+//! The championship as an animated bar chart, bars racing and overtaking
+//! round by round.
+use dioxus::prelude::*;
+use f1_client::{Country, DriverRecords, Record, Round};
+use std::{cell::Cell, collections::BTreeSet, rc::Rc};
+use wasm_bindgen::{prelude::Closure, JsCast};
+
+use crate::{
+    browser,
+    flag::Flag,
+    i18n::{self, Message},
+};
+
+const FRAME_MILLIS: i32 = 800;
+const BAR_HEIGHT: usize = 28;
+const SHOWN_BARS: usize = 10;
+
+/// A driver or team's total after one round.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bar {
+    pub key: String,
+    pub label: String,
+    pub country: Option<Country>,
+    pub points: f64,
+}
+
+/// The standings after each round, leader first.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Frames {
+    pub rounds: Vec<Round>,
+    pub standings: Vec<Vec<Bar>>,
+}
+
+/// Every racer with the points they scored in each round.
+fn frames(racers: Vec<(Bar, Vec<(Round, f64)>)>) -> Frames {
+    let rounds = racers
+        .iter()
+        .flat_map(|(_, scores)| scores.iter().map(|(round, _)| *round))
+        .collect::<BTreeSet<Round>>()
+        .into_iter()
+        .collect::<Vec<Round>>();
+    let standings = rounds
+        .iter()
+        .map(|round| {
+            let mut bars = racers
+                .iter()
+                .map(|(bar, scores)| Bar {
+                    points: scores
+                        .iter()
+                        .filter(|(scored, _)| scored <= round)
+                        .map(|(_, points)| points)
+                        .sum(),
+                    ..bar.clone()
+                })
+                .collect::<Vec<Bar>>();
+            // stable, so ties keep the order the racers came in
+            bars.sort_by(|a, b| b.points.total_cmp(&a.points));
+            bars
+        })
+        .collect();
+
+    Frames { rounds, standings }
+}
+
+/// What a record adds to the championship, its sprint included.
+fn scored(record: &Record) -> f64 {
+    (record.points + record.sprint_points).get()
+}
+
+pub fn driver_frames(drivers: &[DriverRecords]) -> Frames {
+    frames(
+        drivers
+            .iter()
+            .map(|driver| {
+                let bar = Bar {
+                    key: driver.driver.id.clone(),
+                    label: driver.driver.label().to_string(),
+                    country: driver.driver.country(),
+                    points: 0.0,
+                };
+                let scores = driver
+                    .records
+                    .iter()
+                    .map(|record| (record.round, scored(record)))
+                    .collect();
+                (bar, scores)
+            })
+            .collect(),
+    )
+}
+
+/// Teams score what their drivers scored while racing for them.
+pub fn constructor_frames(drivers: &[DriverRecords]) -> Frames {
+    let mut teams = Vec::<(Bar, Vec<(Round, f64)>)>::new();
+    for record in drivers.iter().flat_map(|driver| &driver.records) {
+        let Some(team) = &record.team else {
+            continue;
+        };
+        let score = (record.round, scored(record));
+        match teams.iter_mut().find(|(bar, _)| bar.key == team.id) {
+            Some((_, scores)) => scores.push(score),
+            None => teams.push((
+                Bar {
+                    key: team.id.clone(),
+                    label: team.name.clone(),
+                    country: team.country(),
+                    points: 0.0,
+                },
+                vec![score],
+            )),
+        }
+    }
+    frames(teams)
+}
+
+/// Steps the race on while it's playing, stopping at the last round.
+struct Ticker {
+    window: web_sys::Window,
+    interval: i32,
+    _on_tick: Closure<dyn FnMut()>,
+}
+
+impl Ticker {
+    fn start(
+        frame: UseState<usize>,
+        playing: UseState<bool>,
+        rounds: Rc<Cell<usize>>,
+    ) -> Option<Self> {
+        let window = browser::window()?;
+        let on_tick = Closure::<dyn FnMut()>::new(move || {
+            if !*playing.current() {
+                return;
+            }
+            let last = rounds.get().saturating_sub(1);
+            let next = (*frame.current()).min(last) + 1;
+            if next >= last {
+                playing.set(false);
+            }
+            if next <= last {
+                frame.set(next);
+            }
+        });
+        let interval = window
+            .set_interval_with_callback_and_timeout_and_arguments_0(
+                on_tick.as_ref().unchecked_ref(),
+                FRAME_MILLIS,
+            )
+            .map_err(|_| log::warn!("failed to start the bar chart race"))
+            .ok()?;
+
+        Some(Ticker {
+            window,
+            interval,
+            _on_tick: on_tick,
+        })
+    }
+}
+
+impl Drop for Ticker {
+    fn drop(&mut self) {
+        self.window.clear_interval_with_handle(self.interval);
+    }
+}
+
+#[derive(PartialEq, Props)]
+pub struct BarChartRaceProps<'a> {
+    frames: &'a Frames,
+}
+
+pub fn BarChartRace<'a>(cx: Scope<'a, BarChartRaceProps<'a>>) -> Element {
+    let locale = i18n::use_locale(cx);
+    let frames = cx.props.frames;
+    // starts on the final standings
+    let frame = use_state(cx, || usize::MAX);
+    let playing = use_state(cx, || false);
+    let rounds = cx.use_hook(|| Rc::new(Cell::new(0)));
+    rounds.set(frames.rounds.len());
+    let rounds = rounds.clone();
+    cx.use_hook(|| Ticker::start(frame.clone(), playing.clone(), rounds));
+    // hooks run on every render, so only now is it safe to return early
+    let Some(last) = frames.rounds.len().checked_sub(1) else {
+        return None;
+    };
+
+    let current = (*frame.get()).min(last);
+    let standings = &frames.standings[current];
+    let leader = standings.first().map_or(0.0, |bar| bar.points).max(1.0);
+    // the same element for each racer in every frame, so moving between
+    // frames animates instead of redrawing
+    let mut bars = standings.iter().enumerate().collect::<Vec<(usize, &Bar)>>();
+    bars.sort_by(|(_, a), (_, b)| a.key.cmp(&b.key));
+    let play_label = if *playing.get() {
+        Message::Pause
+    } else {
+        Message::Play
+    };
+
+    cx.render(rsx! {
+        figure {
+            width: "100%",
+            max_width: "600px",
+            aria_label: "{locale.t(Message::ChampionshipRace)}",
+            figcaption {
+                display: "flex",
+                align_items: "center",
+                gap: "10px",
+                button {
+                    onclick: move |_| {
+                        if !*playing.get() && current == last {
+                            frame.set(0);
+                        }
+                        playing.set(!*playing.get());
+                    },
+                    "{locale.t(play_label)}"
+                }
+                input {
+                    r#type: "range",
+                    min: "0",
+                    max: "{last}",
+                    value: "{current}",
+                    aria_label: "{locale.t(Message::Round)}",
+                    aria_valuetext: "{locale.t(Message::Round)} {frames.rounds[current]}",
+                    oninput: move |event| {
+                        if let Ok(index) = event.value.parse::<usize>() {
+                            frame.set(index.min(last));
+                        }
+                    }
+                }
+                span { "{locale.t(Message::Round)} {frames.rounds[current]}" }
+            }
+            div {
+                position: "relative",
+                overflow: "hidden",
+                height: "{SHOWN_BARS * BAR_HEIGHT}px",
+                for (rank, bar) in bars {
+                    div {
+                        key: "{bar.key}",
+                        class: "race-bar",
+                        position: "absolute",
+                        left: "0",
+                        right: "0",
+                        height: "{BAR_HEIGHT - 4}px",
+                        transform: "translateY({rank.min(SHOWN_BARS) * BAR_HEIGHT}px)",
+                        opacity: if rank < SHOWN_BARS { "1" } else { "0" },
+                        div {
+                            class: "race-bar-fill",
+                            height: "100%",
+                            width: "{bar.points / leader * 100.0}%",
+                        }
+                        span {
+                            position: "absolute",
+                            top: "2px",
+                            left: "4px",
+                            white_space: "nowrap",
+                            Flag { country: bar.country }
+                            "{bar.label} · {locale.format_number(bar.points)}"
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing;
+    use f1_client::{Driver, Points};
+
+    fn totals(frame: &[Bar]) -> Vec<(&str, f64)> {
+        frame
+            .iter()
+            .map(|bar| (bar.label.as_str(), bar.points))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn races_drivers_round_by_round() {
+        let drivers = testing::client()
+            .driver_records(testing::YEAR)
+            .await
+            .unwrap();
+
+        let frames = driver_frames(&drivers);
+        assert_eq!(frames.rounds.len(), 3);
+        assert_eq!(
+            totals(&frames.standings[0]),
+            [("HAM", 25.0), ("VER", 18.0), ("BOT", 16.0)]
+        );
+        assert_eq!(
+            totals(&frames.standings[2]),
+            [("HAM", 69.0), ("VER", 61.0), ("BOT", 32.0)]
+        );
+    }
+
+    #[tokio::test]
+    async fn races_teams_round_by_round() {
+        let drivers = testing::client()
+            .driver_records(testing::YEAR)
+            .await
+            .unwrap();
+
+        let frames = constructor_frames(&drivers);
+        assert_eq!(
+            frames
+                .rounds
+                .iter()
+                .map(|round| round.get())
+                .collect::<Vec<u32>>(),
+            [1, 2, 3]
+        );
+        assert_eq!(
+            totals(&frames.standings[2]),
+            [("Mercedes", 101.0), ("Red Bull", 61.0)]
+        );
+    }
+
+    #[test]
+    fn counts_sprint_points() {
+        let driver = |family_name: &str| Driver {
+            id: family_name.to_lowercase(),
+            code: None,
+            given_name: String::new(),
+            family_name: family_name.to_string(),
+            url: None,
+            nationality: None,
+            date_of_birth: None,
+        };
+        let record = |round: &str, points: f64, sprint_points: f64| Record {
+            round: round.to_string().try_into().unwrap(),
+            position: "1".to_string().try_into().unwrap(),
+            points: Points::new(points),
+            sprint_points: Points::new(sprint_points),
+            team: None,
+        };
+        // Verstappen won the first sprint, at Silverstone, and then crashed
+        // out of the race
+        let drivers = [
+            DriverRecords {
+                driver: driver("Verstappen"),
+                records: vec![record("9", 26.0, 0.0), record("10", 0.0, 3.0)],
+            },
+            DriverRecords {
+                driver: driver("Hamilton"),
+                records: vec![record("9", 18.0, 0.0), record("10", 25.0, 2.0)],
+            },
+        ];
+
+        let frames = driver_frames(&drivers);
+        assert_eq!(
+            totals(&frames.standings[1]),
+            [("Hamilton", 45.0), ("Verstappen", 29.0)]
+        );
+    }
+
+    fn Race(cx: Scope) -> Element {
+        testing::use_fixtures(cx);
+        let client = crate::use_client(cx);
+        let frames = use_future(cx, (), |_| async move {
+            client
+                .driver_records(testing::YEAR)
+                .await
+                .map(|drivers| constructor_frames(&drivers))
+        });
+
+        match frames.value() {
+            Some(Ok(frames)) => render! { BarChartRace { frames: frames } },
+            _ => None,
+        }
+    }
+
+    #[tokio::test]
+    async fn starts_on_the_final_standings() {
+        let html = testing::render(Race).await;
+
+        assert!(html.contains("Mercedes · 101"));
+        assert!(html.contains("Red Bull · 61"));
+        assert!(html.contains(i18n::Locale::En.t(Message::Play)));
+    }
+}
@@ -0,0 +1,186 @@
+//! Renders the season pages of past championships to static HTML so visitors
+//! see content before the WASM bundle has loaded.
+//!
+//! Run after `dx build`, which writes the `site/index.html` template:
+//!
+//! ```sh
+//! GQL_ADDR=http://localhost:8080 cargo run --features prerender --bin prerender -- 2010 2022
+//! ```
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+use f1_client::{BoxFuture, Client, HttpTransport, Request, Transport};
+use serde_json::Value;
+use std::{
+    env,
+    error::Error,
+    fs,
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+use web::Route;
+
+const SITE_DIR: &str = "site";
+const MOUNT: &str = r#"<div id="main"></div>"#;
+const FIRST_SEASON: u32 = 1950;
+// how long a page has to stay quiet, with no queries out, to be finished
+const IDLE: Duration = Duration::from_millis(100);
+// how long to wait for outstanding queries before giving up on a page
+const PAGE_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Counts the queries still waiting for the server.
+struct CountingTransport {
+    http: HttpTransport,
+    in_flight: Arc<AtomicUsize>,
+}
+
+impl Transport for CountingTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Value, f1_client::Error>> {
+        let in_flight = InFlight::new(&self.in_flight);
+        Box::pin(async move {
+            // dropping the future early, e.g. when its component unmounts,
+            // still counts the query as done
+            let _in_flight = in_flight;
+            self.http.send(request).await
+        })
+    }
+}
+
+/// One query waiting for the server, for as long as it lives.
+struct InFlight(Arc<AtomicUsize>);
+
+impl InFlight {
+    fn new(count: &Arc<AtomicUsize>) -> Self {
+        count.fetch_add(1, Ordering::SeqCst);
+        InFlight(count.clone())
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[derive(Props)]
+struct PrerenderAppProps {
+    route: Route,
+    in_flight: Arc<AtomicUsize>,
+}
+
+impl PartialEq for PrerenderAppProps {
+    fn eq(&self, other: &Self) -> bool {
+        self.route == other.route && Arc::ptr_eq(&self.in_flight, &other.in_flight)
+    }
+}
+
+fn PrerenderApp(cx: Scope<PrerenderAppProps>) -> Element {
+    web::use_app_providers(cx);
+    let route = cx.props.route.clone();
+
+    render! {
+        Pages { route: route, in_flight: cx.props.in_flight.clone() }
+    }
+}
+
+/// Below the app providers so its client, which keeps count of the queries
+/// out, replaces theirs.
+fn Pages(cx: Scope<PrerenderAppProps>) -> Element {
+    use_context_provider(cx, || {
+        Client::with_transport(CountingTransport {
+            http: HttpTransport::new(env!("GQL_ADDR")),
+            in_flight: cx.props.in_flight.clone(),
+        })
+    });
+    let route = cx.props.route.clone();
+
+    render! {
+        Router::<Route> {
+            config: move || {
+                let history = MemoryHistory::with_initial_path(route.to_string())
+                    .expect("prerendered routes are valid paths");
+                RouterConfig::default().history(history)
+            }
+        }
+    }
+}
+
+async fn render(route: Route) -> String {
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let mut vdom = VirtualDom::new_with_props(
+        PrerenderApp,
+        PrerenderAppProps {
+            route,
+            in_flight: in_flight.clone(),
+        },
+    );
+    let _ = vdom.rebuild();
+    // keep applying updates until the data fetching futures have settled
+    let deadline = Instant::now() + PAGE_TIMEOUT;
+    while Instant::now() < deadline {
+        match tokio::time::timeout(IDLE, vdom.wait_for_work()).await {
+            Ok(()) => {
+                let _ = vdom.render_immediate();
+            }
+            Err(_) if in_flight.load(Ordering::SeqCst) == 0 => break,
+            Err(_) => {}
+        }
+    }
+    dioxus_ssr::pre_render(&vdom)
+}
+
+fn last_season() -> u32 {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("clock is after the epoch")
+        .as_secs();
+    // close enough to know which year we're in
+    1970 + (seconds / 31_556_952) as u32 - 1
+}
+
+fn seasons() -> Result<(u32, u32), Box<dyn Error>> {
+    let mut args = env::args().skip(1);
+    let from = match args.next() {
+        Some(from) => from.parse()?,
+        None => FIRST_SEASON,
+    };
+    let to = match args.next() {
+        Some(to) => to.parse()?,
+        None => last_season(),
+    };
+    Ok((from, to))
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let template = fs::read_to_string(Path::new(SITE_DIR).join("index.html"))?;
+    if !template.contains(MOUNT) {
+        return Err(format!("no {MOUNT} in {SITE_DIR}/index.html, run `dx build` first").into());
+    }
+
+    let (from, to) = seasons()?;
+    for year in from..=to {
+        let year = year.to_string();
+        let routes = [
+            Route::SeasonConstructors { year: year.clone() },
+            Route::SeasonDrivers { year: year.clone() },
+            Route::SeasonSchedule { year },
+        ];
+        for route in routes {
+            let path = route.to_string();
+            let markup = render(route).await;
+            let page = template.replace(MOUNT, &format!(r#"<div id="main">{markup}</div>"#));
+
+            let dir = Path::new(SITE_DIR).join(path.trim_start_matches('/'));
+            fs::create_dir_all(&dir)?;
+            fs::write(dir.join("index.html"), page)?;
+            println!("rendered {path}");
+        }
+    }
+
+    Ok(())
+}
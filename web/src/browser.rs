@@ -0,0 +1,23 @@
+/// The browser window, or `None` when rendering outside of a browser, such as
+/// when prerendering pages.
+pub fn window() -> Option<web_sys::Window> {
+    if cfg!(target_arch = "wasm32") {
+        web_sys::window()
+    } else {
+        None
+    }
+}
+
+/// Today in the visitor's time zone, `None` outside of a browser.
+pub fn today() -> Option<f1_client::RaceDate> {
+    window()?;
+    let now = js_sys::Date::new_0();
+    format!(
+        "{:04}-{:02}-{:02}",
+        now.get_full_year(),
+        now.get_month() + 1,
+        now.get_date()
+    )
+    .try_into()
+    .ok()
+}
@@ -0,0 +1,298 @@
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+use f1_client::{Circuit, Race};
+
+use crate::{
+    a11y,
+    flag::Flag,
+    footer,
+    i18n::{self, Message},
+    theme, use_client, Route,
+};
+
+pub fn CircuitCatalog(cx: Scope) -> Element {
+    let locale = i18n::use_locale(cx);
+    let year = use_state(cx, || "current".to_string());
+    let all_seasons = use_state(cx, || true);
+    let season = if *all_seasons.get() {
+        "all".to_string()
+    } else {
+        year.get().clone()
+    };
+
+    let client = use_client(cx);
+    let future = use_future(cx, (season,), |(season,)| async move {
+        client.circuits(&season).await
+    });
+
+    cx.render(rsx! {
+        div {
+            display: "flex",
+            flex_direction: "column",
+            align_items: "center",
+            h1 {
+                b { "{locale.t(Message::Circuits)}" }
+            }
+            div {
+                input {
+                    r#type: "text",
+                    placeholder: "current",
+                    aria_label: "{locale.t(Message::Season)}",
+                    oninput: move |event| {
+                        year.set(event.value.to_string());
+                    }
+                }
+                label {
+                    input {
+                        r#type: "checkbox",
+                        checked: *all_seasons.get(),
+                        onchange: move |event: Event<FormData>| {
+                            all_seasons.set(event.value == "true");
+                        }
+                    }
+                    " {locale.t(Message::AllSeasons)}"
+                }
+            }
+            match future.value() {
+                Some(Ok(circuits)) => rsx! { ShowCatalog { circuits: circuits } },
+                Some(Err(_)) => rsx! { div { "{locale.t(Message::Error)}" } },
+                None => rsx! { div { "{locale.t(Message::Loading)}" } },
+            }
+        }
+        footer::Footer {}
+    })
+}
+
+#[derive(PartialEq, Props)]
+struct ShowCatalogProps<'a> {
+    circuits: &'a Vec<Circuit>,
+}
+
+fn ShowCatalog<'a>(cx: Scope<'a, ShowCatalogProps<'a>>) -> Element {
+    let locale = i18n::use_locale(cx);
+
+    cx.render(rsx! {
+        table {
+            border_collapse: "collapse",
+            caption {
+                class: a11y::SR_ONLY,
+                "{locale.t(Message::Circuits)}"
+            }
+            thead {
+                tr {
+                    th { scope: "col", "{locale.t(Message::Circuit)}" }
+                    th { scope: "col", "{locale.t(Message::Locality)}" }
+                    th { scope: "col", "{locale.t(Message::Country)}" }
+                    th { scope: "col", "{locale.t(Message::Coordinates)}" }
+                }
+            }
+            tbody {
+                for circuit in cx.props.circuits.iter() {
+                    tr {
+                        class: theme::ROW_CLASS,
+                        th {
+                            scope: "row",
+                            text_align: "left",
+                            if let Some(img_url) = &circuit.img {
+                                rsx! {
+                                    img {
+                                        src: "{img_url}",
+                                        alt: "",
+                                        width: "60",
+                                    }
+                                }
+                            }
+                            Link {
+                                to: Route::CircuitDetail { id: circuit.id.clone() },
+                                "{circuit.name}"
+                            }
+                        }
+                        if let Some(location) = &circuit.location {
+                            rsx! {
+                                td { "{location.locality}" }
+                                td {
+                                    Flag { country: location.country_code() }
+                                    "{location.country}"
+                                }
+                                td { MapLink { circuit: circuit } }
+                            }
+                        } else {
+                            rsx! { td {} td {} td {} }
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[inline_props]
+pub fn CircuitDetail(cx: Scope, id: String) -> Element {
+    let locale = i18n::use_locale(cx);
+    let client = use_client(cx);
+    let future = use_future(cx, (id.clone(),), |(id,)| async move {
+        client.circuit_races(&id).await
+    });
+
+    cx.render(rsx! {
+        div {
+            display: "flex",
+            flex_direction: "column",
+            align_items: "center",
+            match future.value() {
+                Some(Ok(races)) if !races.is_empty() => rsx! { ShowCircuitHistory { races: races } },
+                Some(Ok(_)) => rsx! { h1 { "{locale.t(Message::PageNotFound)}" } },
+                Some(Err(_)) => rsx! { div { "{locale.t(Message::Error)}" } },
+                None => rsx! { div { "{locale.t(Message::Loading)}" } },
+            }
+        }
+        footer::Footer {}
+    })
+}
+
+#[derive(PartialEq, Props)]
+struct ShowCircuitHistoryProps<'a> {
+    races: &'a Vec<Race>,
+}
+
+fn ShowCircuitHistory<'a>(cx: Scope<'a, ShowCircuitHistoryProps<'a>>) -> Element {
+    let locale = i18n::use_locale(cx);
+    let races = cx.props.races;
+    let circuit = &races.last()?.circuit;
+
+    cx.render(rsx! {
+        h1 {
+            match &circuit.url {
+                Some(url) => rsx! {
+                    a {
+                        href: "{url}",
+                        target: "_blank",
+                        b { "{circuit.name}" }
+                    }
+                },
+                None => rsx! { b { "{circuit.name}" } },
+            }
+        }
+        CircuitInfo { circuit: circuit }
+        table {
+            border_collapse: "collapse",
+            caption { "{locale.t(Message::RacesHeld)}" }
+            thead {
+                tr {
+                    th { scope: "col", "{locale.t(Message::Season)}" }
+                    th { scope: "col", "{locale.t(Message::Round)}" }
+                    th { scope: "col", "{locale.t(Message::Name)}" }
+                    th { scope: "col", "{locale.t(Message::Date)}" }
+                }
+            }
+            tbody {
+                // newest first
+                for race in races.iter().rev() {
+                    tr {
+                        class: theme::ROW_CLASS,
+                        text_align: "center",
+                        td { "{race.date.year()}" }
+                        td { "{race.round}" }
+                        th {
+                            scope: "row",
+                            Link {
+                                to: Route::SeasonRound {
+                                    year: race.date.year().to_string(),
+                                    round: race.round.to_string(),
+                                },
+                                "{race.name}"
+                            }
+                        }
+                        td { "{locale.format_date(race.date)}" }
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// The image, location and coordinates of a circuit, for pages that already
+/// show its name.
+#[derive(PartialEq, Props)]
+pub struct CircuitInfoProps<'a> {
+    circuit: &'a Circuit,
+}
+
+pub fn CircuitInfo<'a>(cx: Scope<'a, CircuitInfoProps<'a>>) -> Element {
+    let circuit = cx.props.circuit;
+
+    cx.render(rsx! {
+        if let Some(location) = &circuit.location {
+            rsx! {
+                p {
+                    Flag { country: location.country_code() }
+                    "{location.locality}, {location.country} "
+                    MapLink { circuit: circuit }
+                }
+            }
+        }
+        if let Some(img_url) = &circuit.img {
+            rsx! {
+                img {
+                    src: "{img_url}",
+                    alt: "{circuit.name}",
+                    width: "200",
+                }
+            }
+        }
+    })
+}
+
+#[derive(PartialEq, Props)]
+struct MapLinkProps<'a> {
+    circuit: &'a Circuit,
+}
+
+/// The circuit's coordinates, linking to it on OpenStreetMap.
+fn MapLink<'a>(cx: Scope<'a, MapLinkProps<'a>>) -> Element {
+    let locale = i18n::use_locale(cx);
+    let coordinates = cx.props.circuit.location.as_ref()?.coordinates?;
+    let (lat, long) = (coordinates.lat, coordinates.long);
+
+    cx.render(rsx! {
+        a {
+            href: "https://www.openstreetmap.org/?mlat={lat}&mlon={long}#map=14/{lat}/{long}",
+            target: "_blank",
+            "{locale.format_number(lat)}°, {locale.format_number(long)}°"
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{i18n::Locale, testing};
+
+    #[tokio::test]
+    async fn renders_circuit_catalog() {
+        let html = testing::render_route(Route::CircuitCatalog {}).await;
+
+        assert!(!html.contains(Locale::En.t(Message::Loading)));
+        assert!(html.contains(Locale::En.t(Message::AllSeasons)));
+        assert!(html.contains(r#"href="/circuits/imola""#));
+        assert!(html.contains("Reims-Gueux"));
+        assert!(html.contains("44.3439°, 11.7167°"));
+    }
+
+    #[tokio::test]
+    async fn renders_circuit_history() {
+        let html = testing::render_route(Route::CircuitDetail {
+            id: "bahrain".to_string(),
+        })
+        .await;
+
+        assert!(!html.contains(Locale::En.t(Message::Loading)));
+        assert!(html.contains("Bahrain International Circuit"));
+        assert!(html.contains("Sakhir, Bahrain"));
+        assert!(html.contains(r#"href="/season/2004/round/3""#));
+        // the latest race comes first
+        let latest = html.find("/season/2021/round/1").unwrap();
+        let first = html.find("/season/2004/round/3").unwrap();
+        assert!(latest < first);
+    }
+}
@@ -1,17 +1,32 @@
 use dioxus::prelude::*;
-use graphql_client::{GraphQLQuery, Response};
-use std::error::Error;
+use dioxus_router::prelude::*;
+use f1_client::{Race, Schedule};
 
-use crate::{footer, get_resp_body_from_gql};
+use crate::{
+    a11y,
+    flag::Flag,
+    footer,
+    i18n::{self, Message},
+    theme, use_client, Route,
+};
 
 pub fn CircuitsComponent(cx: Scope) -> Element {
-    let year = use_state(cx, || "current".to_string());
+    render! { CircuitsPage { season: "current".to_string() } }
+}
+
+#[inline_props]
+pub fn SeasonSchedule(cx: Scope, year: String) -> Element {
+    render! { CircuitsPage { season: year.clone() } }
+}
 
+#[inline_props]
+fn CircuitsPage(cx: Scope, season: String) -> Element {
+    let locale = i18n::use_locale(cx);
+    let year = use_state(cx, || season.clone());
+
+    let client = use_client(cx);
     let future = use_future(cx, year, |year| async move {
-        let variables = circuits::Variables {
-            year: year.get().to_string(),
-        };
-        perform_my_query(variables).await.unwrap_or_default()
+        client.schedule(year.get()).await.ok()
     });
 
     cx.render(rsx! {
@@ -20,18 +35,23 @@ pub fn CircuitsComponent(cx: Scope) -> Element {
             flex_direction: "column",
             align_items: "center",
             h1 {
-                b { "Circuits" }
+                b { "{locale.t(Message::Circuits)}" }
+            }
+            Link {
+                to: Route::SeasonSummary { year: year.get().clone() },
+                "{locale.t_with(Message::SeasonSummary, year.get())}"
             }
             input {
                 r#type: "text",
-                placeholder: "current",
+                placeholder: "{season}",
+                aria_label: "{locale.t(Message::Season)}",
                 oninput: move |event| {
                     year.set(event.value.to_string());
                 }
             }
             match future.value() {
-                Some(circuits) if circuits.len() > 0 => rsx! {ShowCircuits { circuits: circuits }},
-                _ => rsx! {render! { "loading" }}
+                Some(Some(schedule)) if schedule.races.len() > 0 => rsx! {ShowCircuits { schedule: schedule }},
+                _ => rsx! {render! { locale.t(Message::Loading) }}
             }
         }
         footer::Footer {}
@@ -40,28 +60,31 @@ pub fn CircuitsComponent(cx: Scope) -> Element {
 
 #[derive(PartialEq, Props)]
 struct ShowCircuitsProps<'a> {
-    circuits: &'a Vec<Option<circuits::CircuitsScheduleRaces>>,
+    schedule: &'a Schedule,
 }
 
 fn ShowCircuits<'a>(cx: Scope<'a, ShowCircuitsProps<'a>>) -> Element {
-    let circuits = cx.props.circuits;
+    let locale = i18n::use_locale(cx);
+    let schedule = cx.props.schedule;
 
     cx.render(rsx! {
         table {
             border_collapse: "collapse",
+            caption {
+                class: a11y::SR_ONLY,
+                "{locale.t(Message::Circuits)}"
+            }
             thead {
                 tr {
-                    th { "Round" }
-                    th { "Name" }
-                    th { "Date" }
-                    th { "Circuit" }
+                    th { scope: "col", "{locale.t(Message::Round)}" }
+                    th { scope: "col", "{locale.t(Message::Name)}" }
+                    th { scope: "col", "{locale.t(Message::Date)}" }
+                    th { scope: "col", "{locale.t(Message::Circuit)}" }
                 }
             }
             tbody {
-                for circuit in circuits {
-                    if let Some(circuit) = circuit {
-                        rsx! {ShowCircuit { circuit: circuit }}
-                    }
+                for circuit in schedule.races.iter() {
+                    ShowCircuit { season: &schedule.season, circuit: circuit }
                 }
             }
         }
@@ -70,87 +93,92 @@ fn ShowCircuits<'a>(cx: Scope<'a, ShowCircuitsProps<'a>>) -> Element {
 
 #[derive(PartialEq, Props)]
 struct ShowCircuitProps<'a> {
-    circuit: &'a circuits::CircuitsScheduleRaces,
+    season: &'a String,
+    circuit: &'a Race,
 }
 
 fn ShowCircuit<'a>(cx: Scope<'a, ShowCircuitProps<'a>>) -> Element {
+    let locale = i18n::use_locale(cx);
     let circuit = cx.props.circuit;
-    let circuit_details = circuit.circuit.as_ref().expect("no details for circuit");
+    let circuit_details = &circuit.circuit;
+    let country = circuit_details
+        .location
+        .as_ref()
+        .and_then(|location| location.country_code());
 
     cx.render(rsx! {
         tr {
-            class: "border-2 hover:bg-gray-100 hover:ring-2 hover:ring-inset",
+            class: theme::ROW_CLASS,
             td {
-                if let Some(round) = &circuit.round {
-                    rsx! {render! { round.to_string() }}
+                Link {
+                    to: Route::SeasonRound {
+                        year: cx.props.season.clone(),
+                        round: circuit.round.to_string(),
+                    },
+                    "{circuit.round}"
                 }
             }
             td {
-                if let (Some(race_name), Some(url)) = (&circuit.race_name, &circuit.url) {
+                if let Some(url) = &circuit.url {
                     rsx! {
                         a {
                             href: "{url}",
                             target: "_blank",
-                            "{race_name}"
+                            "{circuit.name}"
                         }
                     }
                 }
             }
             td {
-                if let Some(date) = &circuit.date {
-                    rsx! {render! { date.to_string() }}
-                }
+                "{locale.format_date(circuit.date)}"
             }
             td {
-                if let (Some(circuit_name), Some(img_url)) = (&circuit_details.circuit_name, &circuit_details.img) {
+                Flag { country: country }
+                if let Some(img_url) = &circuit_details.img {
                     rsx! {
-                        if img_url != "" {
-                            render! {
-                                a {
-                                    class: "group",
-                                    href: "#",
-                                    "{circuit_name}"
-                                    span {
-                                        class: "hidden group-hover:block absolute z-99 bg-white",
-                                        img {
-                                            src: "{img_url}",
-                                            alt: "{circuit_name}",
-                                            width: "100",
-                                        }
-                                    }
+                        a {
+                            class: "group",
+                            href: "#",
+                            "{circuit_details.name}"
+                            span {
+                                class: "hidden group-hover:block absolute z-99",
+                                background_color: theme::SURFACE,
+                                img {
+                                    src: "{img_url}",
+                                    alt: "{circuit_details.name}",
+                                    width: "100",
                                 }
                             }
-                        } else {
-                            render! { circuit_name.to_string() }
                         }
                     }
+                } else {
+                    rsx! { "{circuit_details.name}" }
                 }
             }
         }
     })
 }
 
-#[derive(GraphQLQuery)]
-#[graphql(
-    schema_path = "graph/schema.graphql",
-    query_path = "graph/query.graphql",
-    response_derives = "PartialEq"
-)]
-pub struct Circuits;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{i18n::Locale, testing};
 
-async fn perform_my_query(
-    variables: circuits::Variables,
-) -> Result<Vec<Option<circuits::CircuitsScheduleRaces>>, Box<dyn Error>> {
-    // this is the important line
-    let request_body = Circuits::build_query(variables);
+    #[tokio::test]
+    async fn renders_schedule() {
+        let html = testing::render_route(Route::SeasonSchedule {
+            year: testing::YEAR.to_string(),
+        })
+        .await;
 
-    let response_body: Response<circuits::ResponseData> =
-        get_resp_body_from_gql(&request_body).await.json().await?;
-    Ok(response_body
-        .data
-        .ok_or("missing response data")?
-        .schedule
-        .ok_or("missing schedule")?
-        .races
-        .ok_or("missing races")?)
+        assert!(!html.contains(Locale::En.t(Message::Loading)));
+        assert!(html.contains("Bahrain Grand Prix"));
+        assert!(html.contains("28 Mar 2021"));
+        // only circuits with an image get a hover preview
+        assert!(html.contains(r#"alt="Bahrain International Circuit""#));
+        assert!(!html.contains(r#"alt="Autodromo Enzo e Dino Ferrari""#));
+        assert!(html.contains("Autodromo Enzo e Dino Ferrari"));
+        // rounds open the race weekend page
+        assert!(html.contains(r#"href="/season/2021/round/2""#));
+    }
 }
@@ -1,17 +1,45 @@
 use dioxus::prelude::*;
-use graphql_client::{GraphQLQuery, Response};
-use std::error::Error;
+use f1_client::{ConstructorStanding, Country, Points, Position};
 
-use crate::{footer, get_resp_body_from_gql};
+use crate::{
+    bar_race::{self, BarChartRace},
+    footer,
+    i18n::{self, Message},
+    standings::{StandingRow, StandingsTable},
+    use_client,
+};
+
+const TABLE_ID: &str = "constructors";
 
 pub fn ConstructorsComponent(cx: Scope) -> Element {
-    let year = use_state(cx, || "current".to_string());
+    render! { ConstructorsPage { season: "current".to_string() } }
+}
+
+#[inline_props]
+pub fn SeasonConstructors(cx: Scope, year: String) -> Element {
+    render! { ConstructorsPage { season: year.clone() } }
+}
 
-    let future = use_future(cx, year, |year| async move {
-        let variables = constructors::Variables {
-            year: year.get().to_string(),
-        };
-        perform_my_query(variables).await.unwrap_or_default()
+#[inline_props]
+fn ConstructorsPage(cx: Scope, season: String) -> Element {
+    let locale = i18n::use_locale(cx);
+    let year = use_state(cx, || season.clone());
+
+    let client = use_client(cx);
+    let future = use_future(cx, year, |year| {
+        let client = client.clone();
+        async move {
+            client
+                .constructor_standings(year.get())
+                .await
+                .unwrap_or_default()
+        }
+    });
+    let race_future = use_future(cx, year, |year| async move {
+        client
+            .driver_records(year.get())
+            .await
+            .map(|drivers| bar_race::constructor_frames(&drivers))
     });
 
     cx.render(rsx! {
@@ -20,18 +48,22 @@ pub fn ConstructorsComponent(cx: Scope) -> Element {
             flex_direction: "column",
             align_items: "center",
             h1 {
-                b { "Constructors Standings" }
+                b { "{locale.t(Message::ConstructorsStandings)}" }
             }
             input {
                 r#type: "text",
-                placeholder: "current",
+                placeholder: "{season}",
+                aria_label: "{locale.t(Message::Season)}",
                 oninput: move |event| {
                     year.set(event.value.to_string());
                 }
             }
             match future.value() {
                 Some(constructors) if constructors.len() > 0 => rsx! {ShowConstructors { constructors: constructors }},
-                _ => rsx! {render! { "loading" }}
+                _ => rsx! {render! { locale.t(Message::Loading) }}
+            }
+            if let Some(Ok(frames)) = race_future.value() {
+                rsx! { BarChartRace { frames: frames } }
             }
         }
         footer::Footer {}
@@ -40,95 +72,77 @@ pub fn ConstructorsComponent(cx: Scope) -> Element {
 
 #[derive(PartialEq, Props)]
 struct ShowConstructorsProps<'a> {
-    constructors: &'a Vec<Option<constructors::ConstructorsConstructorStandingsTeams>>,
+    constructors: &'a Vec<ConstructorStanding>,
 }
 
 fn ShowConstructors<'a>(cx: Scope<'a, ShowConstructorsProps<'a>>) -> Element {
-    let constructors = cx.props.constructors;
-
     cx.render(rsx! {
-        table {
-            border_collapse: "collapse",
-            thead {
-                tr {
-                    th { "Position" }
-                    th { "Team" }
-                    th { "Points" }
-                }
-            }
-            tbody {
-                for constructor in constructors {
-                    if let Some(team) = constructor {
-                        rsx! {ShowConstructor { constructor: team }}
-                    }
-                }
-            }
+        StandingsTable {
+            rows: cx.props.constructors.as_slice(),
+            table_id: TABLE_ID,
+            caption: Message::ConstructorsStandings,
+            name_header: Message::Team,
         }
     })
 }
 
-#[derive(PartialEq, Props)]
-struct ShowConstructorProps<'a> {
-    constructor: &'a constructors::ConstructorsConstructorStandingsTeams,
+impl StandingRow for ConstructorStanding {
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn name(&self) -> String {
+        self.team.name.clone()
+    }
+
+    fn link(&self) -> Option<&str> {
+        self.team.url.as_deref()
+    }
+
+    fn points(&self) -> Points {
+        self.points
+    }
+
+    fn wins(&self) -> u32 {
+        self.wins
+    }
+
+    fn country(&self) -> Option<Country> {
+        self.team.country()
+    }
 }
 
-fn ShowConstructor<'a>(cx: Scope<'a, ShowConstructorProps<'a>>) -> Element {
-    let constructor = cx.props.constructor;
-    let team = constructor.team.as_ref().expect("no team");
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{i18n::Locale, testing};
 
-    cx.render(rsx! {
-        tr {
-            class: "border-2 hover:bg-gray-100 hover:ring-2 hover:ring-inset",
-            td {
-                if let Some(position) = &constructor.position {
-                    match position.as_str() {
-                        "1" => rsx! {render! { "🥇" }},
-                        "2" => rsx! {render! { "🥈" }},
-                        "3" => rsx! {render! { "🥉" }},
-                        _ => rsx! {render! { position.to_string() }}
-                    }
-                }
-            }
-            td {
-                if let (Some(name), Some(url)) = (&team.name, &team.url) {
-                    rsx! {render! {
-                        a {
-                            href: "{url}",
-                            target: "_blank",
-                            "{name}"
-                        }
-                    }}
-                }
-            }
-            td {
-                if let Some(points) = &constructor.points {
-                    rsx! {render! { points.to_string() }}
-                }
-            }
+    fn Standings(cx: Scope) -> Element {
+        testing::use_fixtures(cx);
+        let client = use_client(cx);
+        let standings = use_future(cx, (), |_| async move {
+            client.constructor_standings(testing::YEAR).await
+        });
+
+        match standings.value() {
+            Some(Ok(constructors)) => render! { ShowConstructors { constructors: constructors } },
+            _ => render! { Locale::En.t(Message::Loading) },
         }
-    })
-}
+    }
+
+    #[tokio::test]
+    async fn renders_constructor_standings() {
+        let html = testing::render(Standings).await;
 
-#[derive(GraphQLQuery)]
-#[graphql(
-    schema_path = "graph/schema.graphql",
-    query_path = "graph/query.graphql",
-    response_derives = "PartialEq"
-)]
-pub struct Constructors;
-
-async fn perform_my_query(
-    variables: constructors::Variables,
-) -> Result<Vec<Option<constructors::ConstructorsConstructorStandingsTeams>>, Box<dyn Error>> {
-    let request_body = Constructors::build_query(variables);
-
-    let response_body: Response<constructors::ResponseData> =
-        get_resp_body_from_gql(&request_body).await.json().await?;
-    Ok(response_body
-        .data
-        .ok_or("missing response data")?
-        .constructor_standings
-        .ok_or("missing constructor standings")?
-        .teams
-        .ok_or("missing teams")?)
+        assert!(!html.contains(Locale::En.t(Message::Loading)));
+        assert!(html.contains(r#"href="http://en.wikipedia.org/wiki/Red_Bull_Racing""#));
+        assert!(html.contains("Mercedes"));
+        assert!(html.contains("613.5"));
+        assert!(html.contains(Locale::En.t(Message::FirstPlace)));
+        assert!(html.contains(Locale::En.t(Message::Wins)));
+        assert!(html.contains("🇦🇹"));
+        // teams have no code to show or compare by
+        assert!(!html.contains(Locale::En.t(Message::Code)));
+        assert!(!html.contains(r#"type="checkbox""#));
+    }
 }
@@ -1,16 +1,142 @@
 use dioxus::prelude::*;
 use dioxus_charts::LineChart;
-use graphql_client::{GraphQLQuery, Response};
-use std::{
-    collections::{HashMap, HashSet},
-    error::Error,
+use dioxus_router::prelude::*;
+use f1_client::{
+    season_ages, Age, Country, DriverRecords, DriverStanding, Points, Position, Race, SeasonAges,
 };
+use std::collections::HashSet;
 
-use crate::{footer, get_resp_body_from_gql};
+use crate::{
+    a11y,
+    ages::ShowAgeStats,
+    bar_race::{self, BarChartRace, Frames},
+    browser, footer,
+    graph::{self, Frame, GraphMode, HoverLayer},
+    i18n::{self, Message},
+    layout::{self, Breakpoint, SplitView},
+    refresh::{self, LatestRound, Movement, Positions},
+    standings::{StandingRow, StandingsTable},
+    subscription, use_client, Route,
+};
+
+const TABLE_ID: &str = "drivers";
 
 pub fn DriversComponent(cx: Scope) -> Element {
-    let year = use_state(cx, || "current".to_string());
+    render! { DriversPage { season: "current".to_string() } }
+}
+
+#[inline_props]
+pub fn SeasonDrivers(cx: Scope, year: String) -> Element {
+    render! { DriversPage { season: year.clone() } }
+}
+
+#[inline_props]
+fn DriversPage(cx: Scope, season: String) -> Element {
+    let locale = i18n::use_locale(cx);
+    let client = use_client(cx);
+    let year = use_state(cx, || season.clone());
     let compare_drivers = use_state(cx, || HashSet::<String>::new());
+    let hovered_driver = use_state(cx, || None::<String>);
+    let interval = refresh::interval_secs().to_string();
+    let positions = use_ref(cx, Positions::default);
+    let latest_round = use_ref(cx, LatestRound::default);
+    // goes up when a refresh shows another round has been raced
+    let rounds_raced = use_state(cx, || 0_u64);
+
+    let season_future = use_future(
+        cx,
+        (year.get().clone(), *rounds_raced.get()),
+        |(year, _)| {
+            let client = client.clone();
+            async move {
+                let records = client.driver_records(&year).await?;
+                // races only name the rounds, the chart is worth showing without them
+                let races = client
+                    .schedule(&year)
+                    .await
+                    .map(|schedule| schedule.races)
+                    .unwrap_or_default();
+                let ages = season_ages(&records, &races);
+                let race = bar_race::driver_frames(&records);
+                Ok::<_, f1_client::Error>(Season {
+                    records,
+                    races,
+                    ages,
+                    race,
+                })
+            }
+        },
+    );
+    // only a round being raced can change the standings, past seasons never do
+    let live = year.get() == "current"
+        && match (season_future.value(), browser::today()) {
+            (Some(Ok(season)), Some(today)) => {
+                refresh::round_in_progress(&season.races, latest_round.read().round(), today)
+            }
+            _ => false,
+        };
+    let updates = subscription::use_live_updates(cx, live);
+
+    // only the standings are refetched at every refresh
+    let standings_future = use_future(cx, (year.get().clone(), updates.count), |(year, _)| {
+        let client = client.clone();
+        let positions = positions.clone();
+        let latest_round = latest_round.clone();
+        let rounds_raced = rounds_raced.clone();
+        async move {
+            let standings = client.latest_driver_standings(&year).await?;
+            if latest_round.write_silent().update(&year, standings.round) {
+                rounds_raced.modify(|raced| raced + 1);
+            }
+            let moves = positions.write_silent().update(
+                &year,
+                standings
+                    .drivers
+                    .iter()
+                    .map(|standing| (standing.driver.id.clone(), standing.position)),
+            );
+            Ok::<_, f1_client::Error>((standings.drivers, moves))
+        }
+    });
+    // ages only add a column, the standings are worth showing without them
+    let latest_race = match season_future.value() {
+        Some(Ok(season)) => season.ages.latest_race,
+        _ => None,
+    };
+
+    let graph = match season_future.value() {
+        Some(Ok(season)) => rsx! {
+            ShowDriverGraph {
+                records: &season.records,
+                races: &season.races,
+                compare_drivers: compare_drivers,
+                hovered_driver: hovered_driver,
+            }
+        },
+        Some(Err(_)) => rsx! { div { "{locale.t(Message::Error)}" } },
+        None => rsx! { div { "{locale.t(Message::Loading)}" } },
+    };
+    let table = match standings_future.value() {
+        Some(Ok((standings, moves))) => {
+            let rows = standings
+                .iter()
+                .map(|standing| AgedStanding {
+                    age: latest_race.and_then(|date| standing.driver.age_on(date)),
+                    movement: moves.get(&standing.driver.id).copied(),
+                    standing: standing.clone(),
+                })
+                .collect::<Vec<AgedStanding>>();
+            rsx! {
+                ShowDrivers {
+                    rows: rows,
+                    compare_drivers: compare_drivers,
+                    hovered_driver: hovered_driver,
+                }
+            }
+        }
+        Some(Err(_)) => rsx! { div { "{locale.t(Message::Error)}" } },
+        None => rsx! { div { "{locale.t(Message::Loading)}" } },
+    };
 
     cx.render(rsx! {
         div {
@@ -18,296 +144,374 @@ pub fn DriversComponent(cx: Scope) -> Element {
             flex_direction: "column",
             align_items: "center",
             h1 {
-                b { "Drivers Standings" }
+                b { "{locale.t(Message::DriversStandings)}" }
             }
             input {
                 r#type: "text",
-                placeholder: "current",
+                placeholder: "{season}",
+                aria_label: "{locale.t(Message::Season)}",
                 oninput: move |event| {
                     compare_drivers.set(HashSet::<String>::new());
                     year.set(event.value.to_string());
                 }
             }
-            div {
-                display: "flex",
-                flex_direction: "row",
-                ShowDriverGraph { year: year, compare_drivers: compare_drivers },
-                ShowDrivers { year: year, compare_drivers: compare_drivers },
+            if live && updates.pushed {
+                rsx! { p { "{locale.t(Message::LivePushed)}" } }
+            } else if live {
+                rsx! { p { "{locale.t_with(Message::LiveUpdates, &interval)}" } }
+            }
+            SplitView {
+                first_label: locale.t(Message::Chart),
+                second_label: locale.t(Message::Table),
+                first: cx.render(graph),
+                second: cx.render(table),
+            }
+            Link {
+                to: Route::SeasonTeammates { year: year.get().clone() },
+                "{locale.t(Message::TeammateBattles)}"
+            }
+            match season_future.value() {
+                Some(Ok(season)) => rsx! {
+                    BarChartRace { frames: &season.race }
+                    ShowAgeStats { ages: &season.ages }
+                },
+                Some(Err(_)) => rsx! { div { "{locale.t(Message::Error)}" } },
+                None => rsx! { div { "{locale.t(Message::Loading)}" } },
             }
         }
         footer::Footer {}
     })
 }
 
+/// What the chart, the bar chart race, the age column and the age
+/// statistics are drawn from, refetched only when another round has been
+/// raced.
+struct Season {
+    records: Vec<DriverRecords>,
+    races: Vec<Race>,
+    ages: SeasonAges,
+    race: Frames,
+}
+
 #[derive(PartialEq, Props)]
 struct ShowDriverGraphProps<'a> {
-    year: &'a UseState<String>,
+    records: &'a [DriverRecords],
+    /// The season's schedule, to name the rounds.
+    races: &'a [Race],
     compare_drivers: &'a UseState<HashSet<String>>,
+    /// Shared by the chart and the table to highlight one driver in both.
+    hovered_driver: &'a UseState<Option<String>>,
 }
 
 fn ShowDriverGraph<'a>(cx: Scope<'a, ShowDriverGraphProps<'a>>) -> Element {
-    let graph_future = use_future(cx, cx.props.year, |year| async move {
-        let variables = drivers_graph::Variables {
-            year: year.get().to_string(),
-        };
-        driver_graph(variables).await
-    });
+    let locale = i18n::use_locale(cx);
+    let breakpoint = layout::use_breakpoint(cx);
+    let mode = use_state(cx, GraphMode::default);
+    let records = cx.props.records;
+    let races = cx.props.races;
+    let (codes_to_series, rounds) = graph::driver_graph(records, *mode.get());
+    let labels = graph::round_labels(&rounds, races);
+    let (series, series_labels) = if cx.props.compare_drivers.get().len() > 0 {
+        (
+            codes_to_series
+                .iter()
+                .filter(|(code, _)| cx.props.compare_drivers.contains(*code))
+                .map(|(_, series)| series.to_vec())
+                .collect::<Vec<Vec<f32>>>(),
+            codes_to_series
+                .iter()
+                .filter(|(code, _)| cx.props.compare_drivers.contains(*code))
+                .map(|(code, _)| code.to_string())
+                .collect::<Vec<String>>(),
+        )
+    } else {
+        (
+            codes_to_series
+                .iter()
+                .map(|(_, series)| series.to_vec())
+                .collect::<Vec<Vec<f32>>>(),
+            codes_to_series
+                .iter()
+                .map(|(code, _)| code.to_string())
+                .collect::<Vec<String>>(),
+        )
+    };
 
-    cx.render(match graph_future.value() {
-        Some(Ok((codes_to_series, labels))) => {
-            let (series, series_labels) = if cx.props.compare_drivers.get().len() > 0 {
-                (
-                    codes_to_series
-                        .iter()
-                        .filter(|(code, _)| cx.props.compare_drivers.contains(*code))
-                        .map(|(_, series)| series.to_vec())
-                        .collect::<Vec<Vec<f32>>>(),
-                    codes_to_series
-                        .iter()
-                        .filter(|(code, _)| cx.props.compare_drivers.contains(*code))
-                        .map(|(code, _)| code.to_string())
-                        .collect::<Vec<String>>(),
-                )
-            } else {
-                (
-                    codes_to_series
-                        .iter()
-                        .map(|(_, series)| series.to_vec())
-                        .collect::<Vec<Vec<f32>>>(),
-                    codes_to_series
-                        .iter()
-                        .map(|(code, _)| code.to_string())
-                        .collect::<Vec<String>>(),
-                )
-            };
+    // the chart scales to its container; a narrower viewbox keeps the
+    // labels legible on small screens
+    let (viewbox_width, viewbox_height, padding) = match breakpoint {
+        Breakpoint::Mobile => (400, 320, (20, 40, 50, 20)),
+        Breakpoint::Tablet | Breakpoint::Desktop => (600, 400, (30, 65, 80, 30)),
+    };
+    let (padding_top, padding_left, padding_right, padding_bottom) = padding;
+    let frame = Frame::new(
+        (viewbox_width as f32, viewbox_height as f32),
+        (
+            padding_top as f32,
+            padding_left as f32,
+            padding_right as f32,
+            padding_bottom as f32,
+        ),
+        &series,
+    );
 
-            rsx! {
-                LineChart{
-                    series: series,
-                    labels: labels.to_vec(),
-                    series_labels: series_labels.to_vec(),
-                    padding_top: 30,
-                    padding_left: 65,
-                    padding_right: 80,
-                    padding_bottom: 30,
+    let title = locale.t(mode.message());
+
+    cx.render(rsx! {
+        select {
+            aria_label: "{locale.t(Message::ChartMode)}",
+            onchange: move |event| {
+                if let Some(next) = GraphMode::parse(&event.value) {
+                    mode.set(next);
+                }
+            },
+            for candidate in GraphMode::ALL {
+                option {
+                    value: candidate.as_str(),
+                    selected: candidate == *mode.get(),
+                    "{locale.t(candidate.message())}"
                 }
             }
         }
-        Some(Err(_)) => rsx! {
+        figure {
+            width: "100%",
+            aria_label: "{title}",
             div {
-                "error"
+                aria_hidden: "true",
+                position: "relative",
+                LineChart{
+                    series: series.clone(),
+                    labels: labels.to_vec(),
+                    series_labels: series_labels.to_vec(),
+                    label_interpolation: graph::axis_label,
+                    width: "100%",
+                    height: "100%",
+                    viewbox_width: viewbox_width,
+                    viewbox_height: viewbox_height,
+                    padding_top: padding_top,
+                    padding_left: padding_left,
+                    padding_right: padding_right,
+                    padding_bottom: padding_bottom,
+                    lowest: frame.lowest(),
+                    highest: frame.highest(),
+                }
+                HoverLayer {
+                    frame: frame,
+                    mode: *mode.get(),
+                    series: series.clone(),
+                    series_labels: series_labels.clone(),
+                    rounds: rounds.clone(),
+                    races: races,
+                    hovered: cx.props.hovered_driver,
+                }
             }
-        },
-        _ => rsx! {
-            div {
-                "loading"
+            table {
+                class: a11y::SR_ONLY,
+                caption { "{title}" }
+                thead {
+                    tr {
+                        th { scope: "col", "{locale.t(Message::Code)}" }
+                        for (round, label) in rounds.iter().zip(labels.iter()) {
+                            th { scope: "col", "{locale.t(Message::Round)} {round}: {label}" }
+                        }
+                    }
+                }
+                tbody {
+                    for (code, values) in series_labels.iter().zip(series.iter()) {
+                        tr {
+                            th { scope: "row", "{code}" }
+                            for value in values.iter() {
+                                td { "{locale.format_number(f64::from(value.abs()))}" }
+                            }
+                        }
+                    }
+                }
             }
-        },
+        }
     })
 }
 
 #[derive(PartialEq, Props)]
 struct ShowDriversProps<'a> {
-    year: &'a UseState<String>,
+    rows: Vec<AgedStanding>,
     compare_drivers: &'a UseState<HashSet<String>>,
+    /// Shared by the chart and the table to highlight one driver in both.
+    hovered_driver: &'a UseState<Option<String>>,
 }
 
 fn ShowDrivers<'a>(cx: Scope<'a, ShowDriversProps<'a>>) -> Element {
-    let driver_standings_future = use_future(cx, cx.props.year, |year| async move {
-        let variables = drivers::Variables {
-            year: year.get().to_string(),
-        };
-        driver_standings(variables).await
-    });
-
-    cx.render(rsx! {
-        match driver_standings_future.value() {
-            Some(Ok(drivers)) => rsx!(
-                table {
-                    border_collapse: "collapse",
-                    thead {
-                        tr {
-                            th { "Compare" }
-                            th { "Position" }
-                            th { "Code" }
-                            th { "Driver" }
-                            th { "Points" }
-                        }
-                    }
-                    tbody {
-                        for driver in drivers {
-                            if let Some(driver) = driver {
-                                rsx! {ShowDriver { driver: driver, compare_drivers: cx.props.compare_drivers }}
-                            }
-                        }
-                    }
-                }
-            ),
-            Some(Err(_)) => rsx! {
-                tr {
-                    td {
-                        "error"
-                    }
-                }
-            },
-            _ => rsx! {
-                tr {
-                    td {
-                        "loading"
-                    }
-                }
-            },
+    render! {
+        StandingsTable {
+            rows: cx.props.rows.as_slice(),
+            table_id: TABLE_ID,
+            caption: Message::DriversStandings,
+            name_header: Message::Driver,
+            compare: cx.props.compare_drivers,
+            highlight: cx.props.hovered_driver,
         }
-    })
+    }
 }
 
-#[derive(PartialEq, Props)]
-struct ShowDriverProps<'a> {
-    driver: &'a drivers::DriversDriverStandingsDrivers,
-    compare_drivers: &'a UseState<HashSet<String>>,
+/// A standing with the driver's age at the latest race of the season, and
+/// how they moved at the latest refresh.
+#[derive(PartialEq)]
+struct AgedStanding {
+    standing: DriverStanding,
+    age: Option<Age>,
+    movement: Option<Movement>,
 }
 
-fn ShowDriver<'a>(cx: Scope<'a, ShowDriverProps<'a>>) -> Element {
-    let driver = cx.props.driver;
-    let driver_details = driver.driver.as_ref().expect("no details for driver");
+impl StandingRow for AgedStanding {
+    fn position(&self) -> Position {
+        self.standing.position()
+    }
 
-    cx.render(rsx! {
-        tr {
-            class: "border-2 hover:bg-gray-100 hover:ring-2 hover:ring-inset",
-            text_align: "center",
-            td {
-                input {
-                    r#type: "checkbox",
-                    onchange: move |event: Event<FormData>| {
-                        let mut compare_drivers = cx.props.compare_drivers.get().clone();
-                        if event.value == "true" {
-                            compare_drivers.insert(driver_details.code.as_ref().expect("no code").to_string());
-                        } else {
-                            compare_drivers.remove(driver_details.code.as_ref().expect("no code"));
-                        }
-                        cx.props.compare_drivers.set(compare_drivers);
-                    }
-                }
-            }
-            td {
-                if let Some(position) = &driver.position {
-                    match position.as_str() {
-                        "1" => rsx! {render! { "🥇" }},
-                        "2" => rsx! {render! { "🥈" }},
-                        "3" => rsx! {render! { "🥉" }},
-                        _ => rsx! {render! { position.to_string() }}
-                    }
-                }
-            }
-            td {
-                if let Some(code) = &driver_details.code {
-                    rsx! {render! { code.to_string() }}
-                }
-            }
-            td {
-                if let (Some(given_name), Some(family_name), Some(url)) = (&driver_details.given_name, &driver_details.family_name, &driver_details.url) {
-                    rsx! {render! {
-                        a {
-                            href: "{url}",
-                            target: "_blank",
-                            "{given_name} {family_name}"
-                        }
-                    }}
-                }
-            }
-            td {
-                if let Some(points) = &driver.points {
-                    rsx! {render! { points.to_string() }}
-                }
-            }
-        }
-    })
+    fn name(&self) -> String {
+        self.standing.name()
+    }
+
+    fn link(&self) -> Option<&str> {
+        self.standing.link()
+    }
+
+    fn points(&self) -> Points {
+        self.standing.points()
+    }
+
+    fn wins(&self) -> u32 {
+        self.standing.wins()
+    }
+
+    fn code(&self) -> Option<&str> {
+        self.standing.code()
+    }
+
+    fn country(&self) -> Option<Country> {
+        self.standing.country()
+    }
+
+    fn compare_key(&self) -> Option<&str> {
+        self.standing.compare_key()
+    }
+
+    fn age(&self) -> Option<Age> {
+        self.age
+    }
+
+    fn movement(&self) -> Option<Movement> {
+        self.movement
+    }
 }
 
-#[derive(GraphQLQuery)]
-#[graphql(
-    schema_path = "graph/schema.graphql",
-    query_path = "graph/query.graphql",
-    response_derives = "PartialEq,Debug",
-    variables_derives = "Debug"
-)]
-pub struct DriversGraph;
-
-async fn driver_graph(
-    variables: drivers_graph::Variables,
-) -> Result<(HashMap<String, Vec<f32>>, Vec<String>), Box<dyn Error>> {
-    let request_body = DriversGraph::build_query(variables);
-    let response_body: Response<drivers_graph::ResponseData> =
-        get_resp_body_from_gql(&request_body).await.json().await?;
-
-    let drivers = response_body
-        .data
-        .ok_or("missing response data")?
-        .drivers_seasonal_records
-        .ok_or("missing driver standings")?
-        .drivers
-        .ok_or("missing drivers")?;
-
-    let series = drivers
-        .iter()
-        .map(|driver| {
-            let driver = driver.as_ref().expect("no driver");
-            let driver_details = driver.driver.as_ref().expect("no details for driver");
-            let code = driver_details.code.as_ref().expect("no code");
-            let mut sum = 0.0;
-            let records = driver
-                .records
-                .as_ref()
-                .expect("no records")
+impl StandingRow for DriverStanding {
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn name(&self) -> String {
+        self.driver.name()
+    }
+
+    fn link(&self) -> Option<&str> {
+        self.driver.url.as_deref()
+    }
+
+    fn points(&self) -> Points {
+        self.points
+    }
+
+    fn wins(&self) -> u32 {
+        self.wins
+    }
+
+    fn code(&self) -> Option<&str> {
+        self.driver.code.as_deref()
+    }
+
+    fn country(&self) -> Option<Country> {
+        self.driver.country()
+    }
+
+    // the chart labels its lines by code, or family name for drivers without one
+    fn compare_key(&self) -> Option<&str> {
+        Some(self.driver.label())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{i18n::Locale, testing};
+
+    fn Standings(cx: Scope) -> Element {
+        testing::use_fixtures(cx);
+        let compare_drivers = use_state(cx, HashSet::<String>::new);
+        // as if the pointer were over Verstappen's line in the chart
+        let hovered_driver = use_state(cx, || Some("VER".to_string()));
+        let client = use_client(cx);
+        let standings = use_future(cx, (), |_| async move {
+            client.driver_standings(testing::YEAR).await
+        });
+        let rows: Vec<AgedStanding> = match standings.value() {
+            Some(Ok(standings)) => standings
                 .iter()
-                .map(|record| {
-                    let record = record.as_ref().expect("no record");
-                    let points = record.points.as_ref().expect("no points");
-                    sum += points.parse::<f32>().expect("failed to parse points");
-                    sum
+                .map(|standing| AgedStanding {
+                    standing: standing.clone(),
+                    age: None,
+                    movement: None,
                 })
-                .collect::<Vec<f32>>();
-            (code.to_string(), records)
-        })
-        .collect::<HashMap<String, Vec<f32>>>();
-
-    let labels = drivers[0]
-        .as_ref()
-        .expect("no driver")
-        .records
-        .as_ref()
-        .expect("no records")
-        .iter()
-        .map(|record| {
-            let record = record.as_ref().expect("no record");
-            let round = record.round.as_ref().expect("no round");
-            round.to_string()
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        render! {
+            ShowDrivers {
+                rows: rows,
+                compare_drivers: compare_drivers,
+                hovered_driver: hovered_driver,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn renders_driver_standings() {
+        let html = testing::render_route(Route::SeasonDrivers {
+            year: testing::YEAR.to_string(),
         })
-        .collect::<Vec<String>>();
+        .await;
 
-    Ok((series, labels))
-}
+        assert!(!html.contains(Locale::En.t(Message::Loading)));
+        assert!(html.contains(r#"href="http://en.wikipedia.org/wiki/Max_Verstappen""#));
+        assert!(html.contains("Max Verstappen"));
+        assert!(html.contains("395.5"));
+        assert!(html.contains(r#"aria-label="Netherlands""#));
+        // the fixtures have dates of birth, so there's an age column
+        assert!(html.contains(Locale::En.t(Message::Age)));
+        assert!(html.contains(&Locale::En.t_with(Message::CompareDriver, "Lewis Hamilton")));
+        assert!(html.contains(&a11y::row_id(TABLE_ID, 2)));
+    }
+
+    #[tokio::test]
+    async fn highlights_the_hovered_driver() {
+        let html = testing::render(Standings).await;
+
+        assert!(html.contains("Max Verstappen"));
+        assert_eq!(html.matches("highlighted").count(), 1);
+    }
+
+    #[tokio::test]
+    async fn sums_points_by_round() {
+        let records = testing::client()
+            .driver_records(testing::YEAR)
+            .await
+            .unwrap();
+        let (series, rounds) = graph::driver_graph(&records, GraphMode::Cumulative);
 
-#[derive(GraphQLQuery)]
-#[graphql(
-    schema_path = "graph/schema.graphql",
-    query_path = "graph/query.graphql",
-    response_derives = "PartialEq"
-)]
-pub struct Drivers;
-
-async fn driver_standings(
-    variables: drivers::Variables,
-) -> Result<Vec<Option<drivers::DriversDriverStandingsDrivers>>, Box<dyn Error>> {
-    let request_body = Drivers::build_query(variables);
-
-    let response_body: Response<drivers::ResponseData> =
-        get_resp_body_from_gql(&request_body).await.json().await?;
-    Ok(response_body
-        .data
-        .ok_or("missing response data")?
-        .driver_standings
-        .ok_or("missing driver standings")?
-        .drivers
-        .ok_or("missing drivers")?)
+        assert_eq!(
+            rounds.iter().map(|round| round.get()).collect::<Vec<u32>>(),
+            [1, 2, 3]
+        );
+        assert_eq!(series["VER"], [18.0, 43.0, 61.0]);
+        assert_eq!(series["BOT"], [16.0, 16.0, 32.0]);
+    }
 }
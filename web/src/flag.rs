@@ -0,0 +1,24 @@
+use dioxus::prelude::*;
+use f1_client::Country;
+
+#[derive(PartialEq, Props)]
+pub struct FlagProps {
+    /// Nothing is shown for nationalities and countries we can't map.
+    #[props(!optional)]
+    country: Option<Country>,
+}
+
+/// A country's flag emoji, labelled with its name for screen readers.
+pub fn Flag(cx: Scope<FlagProps>) -> Element {
+    let country = cx.props.country?;
+
+    render! {
+        span {
+            role: "img",
+            aria_label: "{country.name()}",
+            title: "{country.name()}",
+            margin_right: "4px",
+            "{country.flag()}"
+        }
+    }
+}
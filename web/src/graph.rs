@@ -0,0 +1,451 @@
+//! What the season charts plot for each driver, round by round, and the
+//! hover layer drawn over them.
+use dioxus::prelude::*;
+use f1_client::{DriverRecords, Race, Record, Round};
+use std::collections::{BTreeSet, HashMap};
+
+use crate::i18n::{self, Message};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum GraphMode {
+    #[default]
+    Cumulative,
+    PerRound,
+    Finishing,
+    Championship,
+    GapToLeader,
+}
+
+impl GraphMode {
+    pub const ALL: [GraphMode; 5] = [
+        GraphMode::Cumulative,
+        GraphMode::PerRound,
+        GraphMode::Finishing,
+        GraphMode::Championship,
+        GraphMode::GapToLeader,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            GraphMode::Cumulative => "cumulative",
+            GraphMode::PerRound => "per-round",
+            GraphMode::Finishing => "finishing",
+            GraphMode::Championship => "championship",
+            GraphMode::GapToLeader => "gap",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        GraphMode::ALL
+            .into_iter()
+            .find(|mode| mode.as_str() == value)
+    }
+
+    pub fn message(self) -> Message {
+        match self {
+            GraphMode::Cumulative => Message::PointsByRound,
+            GraphMode::PerRound => Message::PointsPerRound,
+            GraphMode::Finishing => Message::FinishingPosition,
+            GraphMode::Championship => Message::ChampionshipPosition,
+            GraphMode::GapToLeader => Message::GapToLeader,
+        }
+    }
+
+    /// What the values are, for tooltips.
+    pub fn value_message(self) -> Message {
+        match self {
+            GraphMode::Cumulative | GraphMode::PerRound | GraphMode::GapToLeader => Message::Points,
+            GraphMode::Finishing | GraphMode::Championship => Message::Position,
+        }
+    }
+
+    /// Whether lower is better, so the chart plots negated values to keep
+    /// the best at the top. See [`axis_label`].
+    pub fn inverted(self) -> bool {
+        matches!(
+            self,
+            GraphMode::Finishing | GraphMode::Championship | GraphMode::GapToLeader
+        )
+    }
+}
+
+/// Labels the chart's axis with the value before any inversion.
+pub fn axis_label(value: f32) -> String {
+    format!("{:.0}", value.abs())
+}
+
+fn in_round(records: &[Record], round: Round) -> Option<&Record> {
+    records.iter().find(|record| record.round == round)
+}
+
+/// Each driver's series, keyed by [`f1_client::Driver::label`], and the
+/// rounds they're plotted against, every round any of them raced. Values of
+/// inverted modes are negated. Rounds a driver missed score no points and put
+/// them at the back of the field.
+pub fn driver_graph(
+    drivers: &[DriverRecords],
+    mode: GraphMode,
+) -> (HashMap<String, Vec<f32>>, Vec<Round>) {
+    let rounds = drivers
+        .iter()
+        .flat_map(|driver| &driver.records)
+        .map(|record| record.round)
+        .collect::<BTreeSet<Round>>()
+        .into_iter()
+        .collect::<Vec<Round>>();
+    let totals = drivers
+        .iter()
+        .map(|driver| {
+            let mut sum = 0.0;
+            rounds
+                .iter()
+                .map(|round| {
+                    sum +=
+                        in_round(&driver.records, *round).map_or(0.0, |record| record.points.get());
+                    sum
+                })
+                .collect::<Vec<f64>>()
+        })
+        .collect::<Vec<Vec<f64>>>();
+    let back_of_field = drivers.len() as f64;
+
+    let series = drivers
+        .iter()
+        .zip(&totals)
+        .map(|(driver, own)| {
+            let values = rounds
+                .iter()
+                .enumerate()
+                .map(|(index, round)| {
+                    let record = in_round(&driver.records, *round);
+                    let value = match mode {
+                        GraphMode::Cumulative => own[index],
+                        GraphMode::PerRound => record.map_or(0.0, |record| record.points.get()),
+                        GraphMode::Finishing => {
+                            record.map_or(back_of_field, |record| f64::from(record.position.get()))
+                        }
+                        GraphMode::Championship => {
+                            let ahead = totals.iter().filter(|other| other[index] > own[index]);
+                            (ahead.count() + 1) as f64
+                        }
+                        GraphMode::GapToLeader => {
+                            let leader =
+                                totals.iter().map(|other| other[index]).fold(0.0, f64::max);
+                            leader - own[index]
+                        }
+                    };
+                    if mode.inverted() {
+                        -value as f32
+                    } else {
+                        value as f32
+                    }
+                })
+                .collect::<Vec<f32>>();
+            (driver.driver.label().to_string(), values)
+        })
+        .collect::<HashMap<String, Vec<f32>>>();
+
+    (series, rounds)
+}
+
+fn race(races: &[Race], round: Round) -> Option<&Race> {
+    races.iter().find(|race| race.round == round)
+}
+
+/// Labels the chart's rounds with the flag of the host country and the race
+/// name, or the round number for races missing from the schedule.
+pub fn round_labels(rounds: &[Round], races: &[Race]) -> Vec<String> {
+    rounds
+        .iter()
+        .map(|round| match race(races, *round) {
+            Some(race) => {
+                let name = race.name.strip_suffix(" Grand Prix").unwrap_or(&race.name);
+                let flag = race
+                    .circuit
+                    .location
+                    .as_ref()
+                    .and_then(|location| location.country_code())
+                    .map(|country| format!("{} ", country.flag()))
+                    .unwrap_or_default();
+                format!("{flag}{name}")
+            }
+            None => round.to_string(),
+        })
+        .collect()
+}
+
+/// Where `LineChart` draws its points in its viewbox, given the same size,
+/// padding and value range.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Frame {
+    width: f32,
+    height: f32,
+    /// Top, left, right and bottom.
+    padding: (f32, f32, f32, f32),
+    lowest: f32,
+    highest: f32,
+    rounds: usize,
+}
+
+impl Frame {
+    /// Fits the value range to `series`, always including zero.
+    pub fn new(
+        (width, height): (f32, f32),
+        padding: (f32, f32, f32, f32),
+        series: &[Vec<f32>],
+    ) -> Self {
+        let values = series.iter().flatten().copied();
+        let lowest = values.clone().fold(0.0, f32::min);
+        let highest = values.fold(0.0, f32::max);
+        Frame {
+            width,
+            height,
+            padding,
+            lowest,
+            highest: if highest == lowest {
+                lowest + 1.0
+            } else {
+                highest
+            },
+            rounds: series.iter().map(Vec::len).max().unwrap_or_default(),
+        }
+    }
+
+    /// For `LineChart`, which otherwise fits the range itself.
+    pub fn lowest(&self) -> f32 {
+        self.lowest
+    }
+
+    pub fn highest(&self) -> f32 {
+        self.highest
+    }
+
+    fn x(&self, index: usize) -> f32 {
+        let (_, left, right, _) = self.padding;
+        let steps = self.rounds.saturating_sub(1).max(1) as f32;
+        left + index as f32 * (self.width - left - right) / steps
+    }
+
+    fn y(&self, value: f32) -> f32 {
+        let (top, _, _, bottom) = self.padding;
+        let share = (self.highest - value) / (self.highest - self.lowest);
+        top + share * (self.height - top - bottom)
+    }
+
+    fn polyline(&self, values: &[f32]) -> String {
+        values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| format!("{},{}", self.x(index), self.y(*value)))
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+#[derive(PartialEq, Props)]
+pub struct HoverLayerProps<'a> {
+    frame: Frame,
+    mode: GraphMode,
+    series: Vec<Vec<f32>>,
+    series_labels: Vec<String>,
+    rounds: Vec<Round>,
+    /// The season's schedule, to name the races.
+    races: &'a [Race],
+    /// The line, or table row, under the pointer, by label.
+    hovered: &'a UseState<Option<String>>,
+}
+
+/// Drawn on top of a `LineChart` to show a tooltip for the point under the
+/// pointer and to emphasise the hovered line.
+pub fn HoverLayer<'a>(cx: Scope<'a, HoverLayerProps<'a>>) -> Element {
+    let locale = i18n::use_locale(cx);
+    let point = use_state(cx, || None::<(usize, usize)>);
+    let frame = cx.props.frame;
+    let hovered = cx.props.hovered;
+    let lines = cx.props.series_labels.iter().zip(cx.props.series.iter());
+    let emphasised = hovered.get().as_ref().and_then(|label| {
+        let index = cx
+            .props
+            .series_labels
+            .iter()
+            .position(|known| known == label)?;
+        Some(frame.polyline(&cx.props.series[index]))
+    });
+    let tooltip = (*point.get()).and_then(|(line, round)| {
+        let label = cx.props.series_labels.get(line)?;
+        let value = *cx.props.series.get(line)?.get(round)?;
+        let round_number = *cx.props.rounds.get(round)?;
+        let race = race(cx.props.races, round_number).map(|race| race.name.clone());
+        let (x, y) = (frame.x(round), frame.y(value));
+        // keep the box inside the chart
+        let box_x = if x > frame.width / 2.0 {
+            x - 170.0
+        } else {
+            x + 10.0
+        };
+        let box_y = (y - 30.0).clamp(0.0, frame.height - 60.0);
+        Some((label, round_number, race, value, (x, y), (box_x, box_y)))
+    });
+
+    cx.render(rsx! {
+        svg {
+            view_box: "0 0 {frame.width} {frame.height}",
+            position: "absolute",
+            top: "0",
+            left: "0",
+            width: "100%",
+            height: "100%",
+            if let Some(points) = emphasised {
+                rsx! {
+                    polyline {
+                        points: "{points}",
+                        fill: "none",
+                        stroke: "currentColor",
+                        stroke_width: "3",
+                        pointer_events: "none",
+                    }
+                }
+            }
+            for (line, (label, values)) in lines.enumerate() {
+                g {
+                    key: "{label}",
+                    onmouseenter: move |_| hovered.set(Some(label.clone())),
+                    onmouseleave: move |_| {
+                        hovered.set(None);
+                        point.set(None);
+                    },
+                    polyline {
+                        points: "{frame.polyline(values)}",
+                        fill: "none",
+                        stroke: "transparent",
+                        stroke_width: "12",
+                        pointer_events: "stroke",
+                    }
+                    for (round, value) in values.iter().enumerate() {
+                        circle {
+                            cx: "{frame.x(round)}",
+                            cy: "{frame.y(*value)}",
+                            r: "8",
+                            fill: "transparent",
+                            pointer_events: "all",
+                            onmouseenter: move |_| point.set(Some((line, round))),
+                        }
+                    }
+                }
+            }
+            if let Some((label, round_number, race, value, (x, y), (box_x, box_y))) = tooltip {
+                rsx! {
+                    g {
+                        pointer_events: "none",
+                        font_size: "12",
+                        circle { cx: "{x}", cy: "{y}", r: "4", fill: "currentColor" }
+                        rect {
+                            x: "{box_x}",
+                            y: "{box_y}",
+                            width: "160",
+                            height: "60",
+                            rx: "4",
+                            fill: "var(--surface)",
+                            stroke: "currentColor",
+                        }
+                        text {
+                            x: "{box_x + 8.0}",
+                            y: "{box_y + 17.0}",
+                            fill: "currentColor",
+                            font_weight: "bold",
+                            "{label} · {locale.t(Message::Round)} {round_number}"
+                        }
+                        if let Some(race) = race {
+                            rsx! {
+                                text {
+                                    x: "{box_x + 8.0}",
+                                    y: "{box_y + 34.0}",
+                                    fill: "currentColor",
+                                    "{race}"
+                                }
+                            }
+                        }
+                        text {
+                            x: "{box_x + 8.0}",
+                            y: "{box_y + 51.0}",
+                            fill: "currentColor",
+                            "{locale.t(cx.props.mode.value_message())}: {locale.format_number(f64::from(value.abs()))}"
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing;
+
+    async fn graph(mode: GraphMode) -> HashMap<String, Vec<f32>> {
+        let drivers = testing::client()
+            .driver_records(testing::YEAR)
+            .await
+            .unwrap();
+        driver_graph(&drivers, mode).0
+    }
+
+    #[tokio::test]
+    async fn plots_every_mode_from_the_same_records() {
+        let per_round = graph(GraphMode::PerRound).await;
+        assert_eq!(per_round["VER"], [18.0, 25.0, 18.0]);
+        assert_eq!(per_round["BOT"], [16.0, 0.0, 16.0]);
+
+        // positions are negated so first place is at the top
+        let finishing = graph(GraphMode::Finishing).await;
+        assert_eq!(finishing["BOT"], [-3.0, -19.0, -3.0]);
+
+        let championship = graph(GraphMode::Championship).await;
+        assert_eq!(championship["HAM"], [-1.0, -1.0, -1.0]);
+        assert_eq!(championship["VER"], [-2.0, -2.0, -2.0]);
+
+        let gap = graph(GraphMode::GapToLeader).await;
+        assert_eq!(gap["HAM"], [0.0, 0.0, 0.0]);
+        assert_eq!(gap["VER"], [-7.0, -1.0, -8.0]);
+    }
+
+    #[tokio::test]
+    async fn names_every_round_any_driver_raced() {
+        let client = testing::client();
+        let mut drivers = client.driver_records(testing::YEAR).await.unwrap();
+        // the first driver missing a round doesn't drop it from the chart
+        drivers[0].records.remove(0);
+        let races = client.schedule(testing::YEAR).await.unwrap().races;
+
+        let (series, rounds) = driver_graph(&drivers, GraphMode::Cumulative);
+
+        assert_eq!(series["VER"], [0.0, 25.0, 43.0]);
+        assert_eq!(
+            round_labels(&rounds, &races),
+            ["🇧🇭 Bahrain", "🇮🇹 Emilia Romagna", "🇵🇹 Portuguese"]
+        );
+        assert_eq!(round_labels(&rounds, &[]), ["1", "2", "3"]);
+    }
+
+    #[test]
+    fn places_points_like_the_chart() {
+        let frame = Frame::new(
+            (600.0, 400.0),
+            (30.0, 65.0, 80.0, 30.0),
+            &[vec![18.0, 43.0, 61.0], vec![16.0, 16.0, 32.0]],
+        );
+
+        assert_eq!((frame.lowest, frame.highest, frame.rounds), (0.0, 61.0, 3));
+        assert_eq!(frame.x(0), 65.0);
+        assert_eq!(frame.x(2), 520.0);
+        assert_eq!(frame.y(61.0), 30.0);
+        assert_eq!(frame.y(0.0), 370.0);
+    }
+
+    #[test]
+    fn labels_the_axis_without_the_inversion() {
+        assert_eq!(axis_label(-3.0), "3");
+        assert_eq!(axis_label(61.0), "61");
+    }
+}
@@ -1,15 +1,20 @@
 use dioxus::prelude::*;
 
-use crate::footer;
+use crate::{
+    footer,
+    i18n::{self, Message},
+};
 
 pub fn Home(cx: Scope) -> Element {
+    let locale = i18n::use_locale(cx);
+
     render! {
         div {
             display: "flex",
             flex_direction: "column",
             align_items: "center",
             h1 {
-                b { "Top 5 // todo" }
+                b { "{locale.t(Message::TopFive)}" }
             }
         }
         footer::Footer {}
@@ -0,0 +1,680 @@
+use dioxus::prelude::*;
+use f1_client::{Age, RaceDate};
+
+use crate::{browser, storage};
+
+const STORAGE_KEY: &str = "locale";
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Locale {
+    #[default]
+    En,
+    Es,
+    It,
+    De,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Message {
+    Home,
+    Constructors,
+    Drivers,
+    Schedule,
+    DriversStandings,
+    ConstructorsStandings,
+    Circuits,
+    Loading,
+    Error,
+    Compare,
+    Position,
+    Code,
+    Driver,
+    Team,
+    Points,
+    Round,
+    Name,
+    Date,
+    Circuit,
+    PageNotFound,
+    PageNotFoundDetail,
+    MadeWith,
+    MadeBy,
+    ThemeLight,
+    ThemeDark,
+    ThemeSystem,
+    FirstPlace,
+    SecondPlace,
+    ThirdPlace,
+    CompareDriver,
+    PointsByRound,
+    Language,
+    Theme,
+    Season,
+    Chart,
+    Table,
+    Menu,
+    Breadcrumb,
+    DidYouMean,
+    TryInstead,
+    Wins,
+    RaceResult,
+    StandingsAfterRound,
+    PreviousRound,
+    NextRound,
+    AllSeasons,
+    Locality,
+    Country,
+    Coordinates,
+    RacesHeld,
+    Age,
+    Years,
+    AgeStatistics,
+    YoungestScorer,
+    OldestScorer,
+    YoungestWinner,
+    OldestWinner,
+    AverageAge,
+    SeasonSummary,
+    Champion,
+    RunnerUp,
+    Margin,
+    MostWins,
+    MostPodiums,
+    BestRound,
+    DifferentWinners,
+    ConstructorsChampion,
+    TeammateBattles,
+    PointsShare,
+    RoundsAhead,
+    LiveUpdates,
+    LivePushed,
+    OfflineCached,
+    ChartMode,
+    PointsPerRound,
+    FinishingPosition,
+    ChampionshipPosition,
+    GapToLeader,
+    ChampionshipRace,
+    Play,
+    Pause,
+    TopFive,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 4] = [Locale::En, Locale::Es, Locale::It, Locale::De];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Es => "es",
+            Locale::It => "it",
+            Locale::De => "de",
+        }
+    }
+
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::Es => "Español",
+            Locale::It => "Italiano",
+            Locale::De => "Deutsch",
+        }
+    }
+
+    /// Accepts bare language codes as well as tags such as `de-AT`.
+    pub fn parse(value: &str) -> Option<Self> {
+        let language = value.split(['-', '_']).next()?.to_ascii_lowercase();
+        Locale::ALL
+            .into_iter()
+            .find(|locale| locale.as_str() == language)
+    }
+
+    fn load() -> Self {
+        storage::get(STORAGE_KEY)
+            .or_else(|| browser::window()?.navigator().language())
+            .and_then(|value| Locale::parse(&value))
+            .unwrap_or_default()
+    }
+
+    pub fn t(self, message: Message) -> &'static str {
+        match self {
+            Locale::En => en(message),
+            Locale::Es => es(message),
+            Locale::It => it(message),
+            Locale::De => de(message),
+        }
+    }
+
+    /// Like [`Locale::t`], substituting `value` for the `{}` in the message.
+    pub fn t_with(self, message: Message, value: &str) -> String {
+        self.t(message).replace("{}", value)
+    }
+
+    fn months(self) -> [&'static str; 12] {
+        match self {
+            Locale::En => [
+                "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+            ],
+            Locale::Es => [
+                "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+            ],
+            Locale::It => [
+                "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+            ],
+            Locale::De => [
+                "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.",
+                "Nov.", "Dez.",
+            ],
+        }
+    }
+
+    fn separators(self) -> (char, char) {
+        match self {
+            Locale::En => (',', '.'),
+            Locale::Es | Locale::It | Locale::De => ('.', ','),
+        }
+    }
+
+    pub fn format_date(self, date: RaceDate) -> String {
+        let (year, day) = (date.year(), date.day());
+        let month = self.months()[usize::from(date.month()) - 1];
+        match self {
+            Locale::De => format!("{day}. {month} {year}"),
+            _ => format!("{day} {month} {year}"),
+        }
+    }
+
+    pub fn format_age(self, age: Age) -> String {
+        let Age { years, days } = age;
+        match self {
+            Locale::En => format!("{years} years, {days} days"),
+            Locale::Es => format!("{years} años y {days} días"),
+            Locale::It => format!("{years} anni e {days} giorni"),
+            Locale::De => format!("{years} Jahre, {days} Tage"),
+        }
+    }
+
+    /// Formats a decimal number such as `1250.5` with the locale's separators.
+    pub fn format_number(self, number: f64) -> String {
+        let number = number.to_string();
+        let (sign, number) = match number.strip_prefix('-') {
+            Some(number) => ("-", number),
+            None => ("", number.as_str()),
+        };
+        let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+        let (group, decimal) = self.separators();
+        let mut formatted = sign.to_string();
+        for (i, digit) in whole.chars().enumerate() {
+            if i > 0 && (whole.len() - i) % 3 == 0 {
+                formatted.push(group);
+            }
+            formatted.push(digit);
+        }
+        if !fraction.is_empty() {
+            formatted.push(decimal);
+            formatted.push_str(fraction);
+        }
+        formatted
+    }
+}
+
+fn en(message: Message) -> &'static str {
+    match message {
+        Message::Home => "Home",
+        Message::Constructors => "Constructors",
+        Message::Drivers => "Drivers",
+        Message::Schedule => "Schedule",
+        Message::DriversStandings => "Drivers Standings",
+        Message::ConstructorsStandings => "Constructors Standings",
+        Message::Circuits => "Circuits",
+        Message::Loading => "loading",
+        Message::Error => "error",
+        Message::Compare => "Compare",
+        Message::Position => "Position",
+        Message::Code => "Code",
+        Message::Driver => "Driver",
+        Message::Team => "Team",
+        Message::Points => "Points",
+        Message::Round => "Round",
+        Message::Name => "Name",
+        Message::Date => "Date",
+        Message::Circuit => "Circuit",
+        Message::PageNotFound => "Page not found",
+        Message::PageNotFoundDetail => {
+            "We are terribly sorry, but the page you requested doesn't exist."
+        }
+        Message::MadeWith => "Made with ",
+        Message::MadeBy => " by Alexander Jophus",
+        Message::ThemeLight => "light",
+        Message::ThemeDark => "dark",
+        Message::ThemeSystem => "system",
+        Message::FirstPlace => "1st place",
+        Message::SecondPlace => "2nd place",
+        Message::ThirdPlace => "3rd place",
+        Message::CompareDriver => "Compare {}",
+        Message::PointsByRound => "Cumulative points by round",
+        Message::Language => "Language",
+        Message::Theme => "Theme",
+        Message::Season => "Season",
+        Message::Chart => "Chart",
+        Message::Table => "Table",
+        Message::Menu => "Menu",
+        Message::Breadcrumb => "Breadcrumb",
+        Message::DidYouMean => "Did you mean {}?",
+        Message::TryInstead => "Try one of these pages instead:",
+        Message::Wins => "Wins",
+        Message::RaceResult => "Race result",
+        Message::StandingsAfterRound => "Standings after round {}",
+        Message::PreviousRound => "Previous round",
+        Message::NextRound => "Next round",
+        Message::AllSeasons => "All seasons",
+        Message::Locality => "Locality",
+        Message::Country => "Country",
+        Message::Coordinates => "Coordinates",
+        Message::RacesHeld => "Races held here",
+        Message::Age => "Age",
+        Message::Years => "years",
+        Message::AgeStatistics => "Age statistics",
+        Message::YoungestScorer => "Youngest points scorer",
+        Message::OldestScorer => "Oldest points scorer",
+        Message::YoungestWinner => "Youngest winner",
+        Message::OldestWinner => "Oldest winner",
+        Message::AverageAge => "Average grid age",
+        Message::SeasonSummary => "{} season summary",
+        Message::Champion => "Champion",
+        Message::RunnerUp => "Runner-up",
+        Message::Margin => "Margin",
+        Message::MostWins => "Most wins",
+        Message::MostPodiums => "Most podiums",
+        Message::BestRound => "Most points in a round",
+        Message::DifferentWinners => "Different winners",
+        Message::ConstructorsChampion => "Constructors' champion",
+        Message::TeammateBattles => "Teammate battles",
+        Message::PointsShare => "Points share",
+        Message::RoundsAhead => "Rounds ahead",
+        Message::LiveUpdates => "Live, updates every {} s",
+        Message::LivePushed => "Live",
+        Message::OfflineCached => "Offline – showing cached data from {}",
+        Message::ChartMode => "Chart mode",
+        Message::PointsPerRound => "Points per round",
+        Message::FinishingPosition => "Finishing position by round",
+        Message::ChampionshipPosition => "Championship position by round",
+        Message::GapToLeader => "Gap to leader by round",
+        Message::ChampionshipRace => "Championship race",
+        Message::Play => "Play",
+        Message::Pause => "Pause",
+        Message::TopFive => "Top 5",
+    }
+}
+
+fn es(message: Message) -> &'static str {
+    match message {
+        Message::Home => "Inicio",
+        Message::Constructors => "Constructores",
+        Message::Drivers => "Pilotos",
+        Message::Schedule => "Calendario",
+        Message::DriversStandings => "Clasificación de pilotos",
+        Message::ConstructorsStandings => "Clasificación de constructores",
+        Message::Circuits => "Circuitos",
+        Message::Loading => "cargando",
+        Message::Error => "error",
+        Message::Compare => "Comparar",
+        Message::Position => "Posición",
+        Message::Code => "Código",
+        Message::Driver => "Piloto",
+        Message::Team => "Equipo",
+        Message::Points => "Puntos",
+        Message::Round => "Ronda",
+        Message::Name => "Nombre",
+        Message::Date => "Fecha",
+        Message::Circuit => "Circuito",
+        Message::PageNotFound => "Página no encontrada",
+        Message::PageNotFoundDetail => "Lo sentimos mucho, pero la página que buscas no existe.",
+        Message::MadeWith => "Hecho con ",
+        Message::MadeBy => " por Alexander Jophus",
+        Message::ThemeLight => "claro",
+        Message::ThemeDark => "oscuro",
+        Message::ThemeSystem => "sistema",
+        Message::FirstPlace => "1.º puesto",
+        Message::SecondPlace => "2.º puesto",
+        Message::ThirdPlace => "3.er puesto",
+        Message::CompareDriver => "Comparar a {}",
+        Message::PointsByRound => "Puntos acumulados por ronda",
+        Message::Language => "Idioma",
+        Message::Theme => "Tema",
+        Message::Season => "Temporada",
+        Message::Chart => "Gráfico",
+        Message::Table => "Tabla",
+        Message::Menu => "Menú",
+        Message::Breadcrumb => "Ruta de navegación",
+        Message::DidYouMean => "¿Quisiste decir {}?",
+        Message::TryInstead => "Prueba con una de estas páginas:",
+        Message::Wins => "Victorias",
+        Message::RaceResult => "Resultado de la carrera",
+        Message::StandingsAfterRound => "Clasificación tras la ronda {}",
+        Message::PreviousRound => "Ronda anterior",
+        Message::NextRound => "Ronda siguiente",
+        Message::AllSeasons => "Todas las temporadas",
+        Message::Locality => "Localidad",
+        Message::Country => "País",
+        Message::Coordinates => "Coordenadas",
+        Message::RacesHeld => "Carreras disputadas aquí",
+        Message::Age => "Edad",
+        Message::Years => "años",
+        Message::AgeStatistics => "Estadísticas de edad",
+        Message::YoungestScorer => "Piloto más joven en puntuar",
+        Message::OldestScorer => "Piloto más veterano en puntuar",
+        Message::YoungestWinner => "Ganador más joven",
+        Message::OldestWinner => "Ganador más veterano",
+        Message::AverageAge => "Edad media de la parrilla",
+        Message::SeasonSummary => "Resumen de la temporada {}",
+        Message::Champion => "Campeón",
+        Message::RunnerUp => "Subcampeón",
+        Message::Margin => "Diferencia",
+        Message::MostWins => "Más victorias",
+        Message::MostPodiums => "Más podios",
+        Message::BestRound => "Más puntos en una ronda",
+        Message::DifferentWinners => "Ganadores distintos",
+        Message::ConstructorsChampion => "Campeón de constructores",
+        Message::TeammateBattles => "Duelos entre compañeros",
+        Message::PointsShare => "Reparto de puntos",
+        Message::RoundsAhead => "Rondas por delante",
+        Message::LiveUpdates => "En directo, se actualiza cada {} s",
+        Message::LivePushed => "En directo",
+        Message::OfflineCached => "Sin conexión: mostrando datos guardados del {}",
+        Message::ChartMode => "Modo del gráfico",
+        Message::PointsPerRound => "Puntos por ronda",
+        Message::FinishingPosition => "Posición de llegada por ronda",
+        Message::ChampionshipPosition => "Posición en el campeonato por ronda",
+        Message::GapToLeader => "Diferencia con el líder por ronda",
+        Message::ChampionshipRace => "Carrera por el campeonato",
+        Message::Play => "Reproducir",
+        Message::Pause => "Pausa",
+        Message::TopFive => "Los 5 mejores",
+    }
+}
+
+fn it(message: Message) -> &'static str {
+    match message {
+        Message::Home => "Home",
+        Message::Constructors => "Costruttori",
+        Message::Drivers => "Piloti",
+        Message::Schedule => "Calendario",
+        Message::DriversStandings => "Classifica piloti",
+        Message::ConstructorsStandings => "Classifica costruttori",
+        Message::Circuits => "Circuiti",
+        Message::Loading => "caricamento",
+        Message::Error => "errore",
+        Message::Compare => "Confronta",
+        Message::Position => "Posizione",
+        Message::Code => "Sigla",
+        Message::Driver => "Pilota",
+        Message::Team => "Scuderia",
+        Message::Points => "Punti",
+        Message::Round => "Gara",
+        Message::Name => "Nome",
+        Message::Date => "Data",
+        Message::Circuit => "Circuito",
+        Message::PageNotFound => "Pagina non trovata",
+        Message::PageNotFoundDetail => "Siamo spiacenti, ma la pagina richiesta non esiste.",
+        Message::MadeWith => "Fatto con ",
+        Message::MadeBy => " da Alexander Jophus",
+        Message::ThemeLight => "chiaro",
+        Message::ThemeDark => "scuro",
+        Message::ThemeSystem => "sistema",
+        Message::FirstPlace => "1º posto",
+        Message::SecondPlace => "2º posto",
+        Message::ThirdPlace => "3º posto",
+        Message::CompareDriver => "Confronta {}",
+        Message::PointsByRound => "Punti cumulativi per gara",
+        Message::Language => "Lingua",
+        Message::Theme => "Tema",
+        Message::Season => "Stagione",
+        Message::Chart => "Grafico",
+        Message::Table => "Tabella",
+        Message::Menu => "Menu",
+        Message::Breadcrumb => "Percorso di navigazione",
+        Message::DidYouMean => "Forse cercavi {}?",
+        Message::TryInstead => "Prova invece una di queste pagine:",
+        Message::Wins => "Vittorie",
+        Message::RaceResult => "Risultato della gara",
+        Message::StandingsAfterRound => "Classifica dopo il round {}",
+        Message::PreviousRound => "Round precedente",
+        Message::NextRound => "Round successivo",
+        Message::AllSeasons => "Tutte le stagioni",
+        Message::Locality => "Località",
+        Message::Country => "Paese",
+        Message::Coordinates => "Coordinate",
+        Message::RacesHeld => "Gare disputate qui",
+        Message::Age => "Età",
+        Message::Years => "anni",
+        Message::AgeStatistics => "Statistiche sull'età",
+        Message::YoungestScorer => "Pilota più giovane a punti",
+        Message::OldestScorer => "Pilota più anziano a punti",
+        Message::YoungestWinner => "Vincitore più giovane",
+        Message::OldestWinner => "Vincitore più anziano",
+        Message::AverageAge => "Età media della griglia",
+        Message::SeasonSummary => "Riepilogo della stagione {}",
+        Message::Champion => "Campione",
+        Message::RunnerUp => "Secondo classificato",
+        Message::Margin => "Distacco",
+        Message::MostWins => "Più vittorie",
+        Message::MostPodiums => "Più podi",
+        Message::BestRound => "Più punti in un round",
+        Message::DifferentWinners => "Vincitori diversi",
+        Message::ConstructorsChampion => "Campione costruttori",
+        Message::TeammateBattles => "Sfide tra compagni",
+        Message::PointsShare => "Quota punti",
+        Message::RoundsAhead => "Round davanti",
+        Message::LiveUpdates => "In diretta, si aggiorna ogni {} s",
+        Message::LivePushed => "In diretta",
+        Message::OfflineCached => "Offline: dati salvati del {}",
+        Message::ChartMode => "Modalità del grafico",
+        Message::PointsPerRound => "Punti per gara",
+        Message::FinishingPosition => "Posizione d'arrivo per gara",
+        Message::ChampionshipPosition => "Posizione in campionato per gara",
+        Message::GapToLeader => "Distacco dal leader per gara",
+        Message::ChampionshipRace => "Corsa al titolo",
+        Message::Play => "Riproduci",
+        Message::Pause => "Pausa",
+        Message::TopFive => "I primi 5",
+    }
+}
+
+fn de(message: Message) -> &'static str {
+    match message {
+        Message::Home => "Start",
+        Message::Constructors => "Konstrukteure",
+        Message::Drivers => "Fahrer",
+        Message::Schedule => "Rennkalender",
+        Message::DriversStandings => "Fahrerwertung",
+        Message::ConstructorsStandings => "Konstrukteurswertung",
+        Message::Circuits => "Strecken",
+        Message::Loading => "lädt",
+        Message::Error => "Fehler",
+        Message::Compare => "Vergleichen",
+        Message::Position => "Position",
+        Message::Code => "Kürzel",
+        Message::Driver => "Fahrer",
+        Message::Team => "Team",
+        Message::Points => "Punkte",
+        Message::Round => "Lauf",
+        Message::Name => "Name",
+        Message::Date => "Datum",
+        Message::Circuit => "Strecke",
+        Message::PageNotFound => "Seite nicht gefunden",
+        Message::PageNotFoundDetail => {
+            "Es tut uns leid, aber die angeforderte Seite existiert nicht."
+        }
+        Message::MadeWith => "Gemacht mit ",
+        Message::MadeBy => " von Alexander Jophus",
+        Message::ThemeLight => "hell",
+        Message::ThemeDark => "dunkel",
+        Message::ThemeSystem => "System",
+        Message::FirstPlace => "1. Platz",
+        Message::SecondPlace => "2. Platz",
+        Message::ThirdPlace => "3. Platz",
+        Message::CompareDriver => "{} vergleichen",
+        Message::PointsByRound => "Kumulierte Punkte pro Lauf",
+        Message::Language => "Sprache",
+        Message::Theme => "Design",
+        Message::Season => "Saison",
+        Message::Chart => "Diagramm",
+        Message::Table => "Tabelle",
+        Message::Menu => "Menü",
+        Message::Breadcrumb => "Brotkrümelnavigation",
+        Message::DidYouMean => "Meintest du {}?",
+        Message::TryInstead => "Versuche stattdessen eine dieser Seiten:",
+        Message::Wins => "Siege",
+        Message::RaceResult => "Rennergebnis",
+        Message::StandingsAfterRound => "Wertung nach Runde {}",
+        Message::PreviousRound => "Vorherige Runde",
+        Message::NextRound => "Nächste Runde",
+        Message::AllSeasons => "Alle Saisons",
+        Message::Locality => "Ort",
+        Message::Country => "Land",
+        Message::Coordinates => "Koordinaten",
+        Message::RacesHeld => "Hier ausgetragene Rennen",
+        Message::Age => "Alter",
+        Message::Years => "Jahre",
+        Message::AgeStatistics => "Altersstatistik",
+        Message::YoungestScorer => "Jüngster Punktesammler",
+        Message::OldestScorer => "Ältester Punktesammler",
+        Message::YoungestWinner => "Jüngster Sieger",
+        Message::OldestWinner => "Ältester Sieger",
+        Message::AverageAge => "Durchschnittsalter im Feld",
+        Message::SeasonSummary => "Saisonrückblick {}",
+        Message::Champion => "Weltmeister",
+        Message::RunnerUp => "Vizeweltmeister",
+        Message::Margin => "Abstand",
+        Message::MostWins => "Meiste Siege",
+        Message::MostPodiums => "Meiste Podestplätze",
+        Message::BestRound => "Meiste Punkte in einer Runde",
+        Message::DifferentWinners => "Verschiedene Sieger",
+        Message::ConstructorsChampion => "Konstrukteursweltmeister",
+        Message::TeammateBattles => "Teamkollegen-Duelle",
+        Message::PointsShare => "Punkteanteil",
+        Message::RoundsAhead => "Runden vorne",
+        Message::LiveUpdates => "Live, aktualisiert alle {} s",
+        Message::LivePushed => "Live",
+        Message::OfflineCached => "Offline – zwischengespeicherte Daten vom {}",
+        Message::ChartMode => "Diagrammmodus",
+        Message::PointsPerRound => "Punkte pro Lauf",
+        Message::FinishingPosition => "Zielposition pro Lauf",
+        Message::ChampionshipPosition => "Meisterschaftsposition pro Lauf",
+        Message::GapToLeader => "Rückstand auf den Führenden pro Lauf",
+        Message::ChampionshipRace => "Kampf um die Meisterschaft",
+        Message::Play => "Abspielen",
+        Message::Pause => "Pause",
+        Message::TopFive => "Die Top 5",
+    }
+}
+
+pub fn use_locale_provider(cx: &ScopeState) {
+    use_shared_state_provider(cx, Locale::load);
+    let current = use_locale(cx);
+
+    use_effect(cx, (&current,), |(current,)| async move {
+        let root = browser::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.document_element());
+        if let Some(root) = root {
+            if root.set_attribute("lang", current.as_str()).is_err() {
+                log::warn!("failed to set document language");
+            }
+        }
+    });
+}
+
+pub fn use_locale(cx: &ScopeState) -> Locale {
+    use_shared_state::<Locale>(cx)
+        .map(|locale| *locale.read())
+        .unwrap_or_default()
+}
+
+pub fn LocaleSwitcher(cx: Scope) -> Element {
+    let locale = use_shared_state::<Locale>(cx)?;
+    let current = *locale.read();
+
+    render! {
+        select {
+            aria_label: "{current.t(Message::Language)}",
+            onchange: move |event| {
+                if let Some(next) = Locale::parse(&event.value) {
+                    storage::set(STORAGE_KEY, next.as_str());
+                    *locale.write() = next;
+                }
+            },
+            for candidate in Locale::ALL {
+                option {
+                    value: candidate.as_str(),
+                    selected: candidate == current,
+                    "{candidate.native_name()}"
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: &str) -> RaceDate {
+        day.to_string().try_into().unwrap()
+    }
+
+    #[test]
+    fn parses_language_tags() {
+        assert_eq!(Locale::parse("en"), Some(Locale::En));
+        assert_eq!(Locale::parse("es-419"), Some(Locale::Es));
+        assert_eq!(Locale::parse("it_IT"), Some(Locale::It));
+        assert_eq!(Locale::parse("DE-AT"), Some(Locale::De));
+    }
+
+    #[test]
+    fn falls_back_to_english() {
+        assert_eq!(Locale::parse("fr-FR"), None);
+        assert_eq!(Locale::parse(""), None);
+        assert_eq!(Locale::parse("fr-FR").unwrap_or_default(), Locale::En);
+    }
+
+    #[test]
+    fn formats_numbers_in_english() {
+        assert_eq!(Locale::En.format_number(25.0), "25");
+        assert_eq!(Locale::En.format_number(395.5), "395.5");
+        assert_eq!(Locale::En.format_number(1250.5), "1,250.5");
+        assert_eq!(Locale::En.format_number(1234567.0), "1,234,567");
+        assert_eq!(Locale::En.format_number(-1250.0), "-1,250");
+    }
+
+    #[test]
+    fn formats_numbers_in_spanish() {
+        assert_eq!(Locale::Es.format_number(395.5), "395,5");
+        assert_eq!(Locale::Es.format_number(1250.5), "1.250,5");
+        assert_eq!(Locale::Es.format_number(-0.5), "-0,5");
+    }
+
+    #[test]
+    fn formats_numbers_in_italian() {
+        assert_eq!(Locale::It.format_number(395.5), "395,5");
+        assert_eq!(Locale::It.format_number(1234567.25), "1.234.567,25");
+    }
+
+    #[test]
+    fn formats_numbers_in_german() {
+        assert_eq!(Locale::De.format_number(395.5), "395,5");
+        assert_eq!(Locale::De.format_number(100000.0), "100.000");
+    }
+
+    #[test]
+    fn formats_dates_in_every_locale() {
+        let races = [date("2021-03-28"), date("2021-12-05")];
+        let formatted = |locale: Locale| races.map(|race| locale.format_date(race));
+
+        assert_eq!(formatted(Locale::En), ["28 Mar 2021", "5 Dec 2021"]);
+        assert_eq!(formatted(Locale::Es), ["28 mar 2021", "5 dic 2021"]);
+        assert_eq!(formatted(Locale::It), ["28 mar 2021", "5 dic 2021"]);
+        assert_eq!(formatted(Locale::De), ["28. März 2021", "5. Dez. 2021"]);
+    }
+}
@@ -0,0 +1,138 @@
+use dioxus::prelude::*;
+use wasm_bindgen::{prelude::Closure, JsCast};
+
+use crate::browser;
+
+const TABLET_WIDTH: f64 = 640.0;
+const DESKTOP_WIDTH: f64 = 1024.0;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Breakpoint {
+    Mobile,
+    Tablet,
+    Desktop,
+}
+
+impl Breakpoint {
+    fn from_width(width: f64) -> Self {
+        if width < TABLET_WIDTH {
+            Breakpoint::Mobile
+        } else if width < DESKTOP_WIDTH {
+            Breakpoint::Tablet
+        } else {
+            Breakpoint::Desktop
+        }
+    }
+}
+
+fn window_width() -> f64 {
+    browser::window()
+        .and_then(|window| window.inner_width().ok())
+        .and_then(|width| width.as_f64())
+        .unwrap_or(DESKTOP_WIDTH)
+}
+
+pub fn use_breakpoint_provider(cx: &ScopeState) {
+    use_shared_state_provider(cx, || Breakpoint::from_width(window_width()));
+    let breakpoint = use_shared_state::<Breakpoint>(cx).expect("breakpoint was just provided");
+
+    cx.use_hook(|| {
+        let Some(window) = browser::window() else {
+            return;
+        };
+        let breakpoint = breakpoint.clone();
+        let listener = Closure::<dyn FnMut()>::new(move || {
+            let next = Breakpoint::from_width(window_width());
+            // only re-render subscribers when a breakpoint is crossed
+            if *breakpoint.read() != next {
+                *breakpoint.write() = next;
+            }
+        });
+        let added =
+            window.add_event_listener_with_callback("resize", listener.as_ref().unchecked_ref());
+        if added.is_err() {
+            log::warn!("failed to listen for window resizes");
+        }
+        listener.forget();
+    });
+}
+
+pub fn use_breakpoint(cx: &ScopeState) -> Breakpoint {
+    use_shared_state::<Breakpoint>(cx)
+        .map(|breakpoint| *breakpoint.read())
+        .unwrap_or(Breakpoint::Desktop)
+}
+
+#[derive(Props)]
+pub struct SplitViewProps<'a> {
+    first_label: &'a str,
+    second_label: &'a str,
+    first: Element<'a>,
+    second: Element<'a>,
+}
+
+/// Lays out two panes side by side on desktop, stacked on tablets and as tabs
+/// on phones.
+pub fn SplitView<'a>(cx: Scope<'a, SplitViewProps<'a>>) -> Element<'a> {
+    let breakpoint = use_breakpoint(cx);
+    let show_second = use_state(cx, || false);
+
+    match breakpoint {
+        Breakpoint::Mobile => cx.render(rsx! {
+            div {
+                width: "100%",
+                div {
+                    role: "tablist",
+                    display: "flex",
+                    flex_direction: "row",
+                    justify_content: "center",
+                    button {
+                        role: "tab",
+                        padding: "5px 15px",
+                        aria_selected: "{!show_second.get()}",
+                        font_weight: if *show_second.get() { "normal" } else { "bold" },
+                        onclick: move |_| show_second.set(false),
+                        "{cx.props.first_label}"
+                    }
+                    button {
+                        role: "tab",
+                        padding: "5px 15px",
+                        aria_selected: "{show_second.get()}",
+                        font_weight: if *show_second.get() { "bold" } else { "normal" },
+                        onclick: move |_| show_second.set(true),
+                        "{cx.props.second_label}"
+                    }
+                }
+                div {
+                    role: "tabpanel",
+                    width: "100%",
+                    overflow_x: "auto",
+                    if *show_second.get() {
+                        rsx! { &cx.props.second }
+                    } else {
+                        rsx! { &cx.props.first }
+                    }
+                }
+            }
+        }),
+        Breakpoint::Tablet => cx.render(rsx! {
+            div {
+                display: "flex",
+                flex_direction: "column",
+                align_items: "center",
+                width: "100%",
+                div { width: "100%", &cx.props.first }
+                &cx.props.second
+            }
+        }),
+        Breakpoint::Desktop => cx.render(rsx! {
+            div {
+                display: "flex",
+                flex_direction: "row",
+                width: "100%",
+                div { flex: "1", &cx.props.first }
+                &cx.props.second
+            }
+        }),
+    }
+}
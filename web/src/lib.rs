@@ -0,0 +1,154 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+
+mod a11y;
+mod ages;
+mod bar_race;
+mod browser;
+mod catalog;
+use catalog::{CircuitCatalog, CircuitDetail};
+mod circuits;
+use circuits::{CircuitsComponent, SeasonSchedule};
+mod constructors;
+use constructors::{ConstructorsComponent, SeasonConstructors};
+mod drivers;
+use drivers::{DriversComponent, SeasonDrivers};
+mod home;
+use home::Home;
+mod flag;
+mod footer;
+mod graph;
+mod i18n;
+use i18n::Message;
+mod layout;
+mod nav;
+use nav::NavBar;
+mod offline;
+mod race;
+use race::SeasonRound;
+mod refresh;
+mod standings;
+mod storage;
+mod subscription;
+mod suggest;
+mod summary;
+use summary::SeasonSummary;
+mod teammates;
+use teammates::SeasonTeammates;
+#[cfg(test)]
+mod testing;
+mod theme;
+
+#[derive(Routable, Clone, PartialEq)]
+#[rustfmt::skip]
+pub enum Route {
+    #[layout(NavBar)]
+        #[route("/")]
+        Home {},
+        #[route("/constructors")]
+        ConstructorsComponent {},
+        #[route("/drivers")]
+        DriversComponent {},
+        #[route("/schedule")]
+        CircuitsComponent {},
+        #[route("/circuits")]
+        CircuitCatalog {},
+        #[route("/circuits/:id")]
+        CircuitDetail { id: String },
+        #[nest("/season/:year")]
+            #[route("/constructors")]
+            SeasonConstructors { year: String },
+            #[route("/drivers")]
+            SeasonDrivers { year: String },
+            #[route("/schedule")]
+            SeasonSchedule { year: String },
+            #[route("/round/:round")]
+            SeasonRound { year: String, round: String },
+            #[route("/summary")]
+            SeasonSummary { year: String },
+            #[route("/teammates")]
+            SeasonTeammates { year: String },
+        #[end_nest]
+    #[end_layout]
+    #[route("/:..segments")]
+    PageNotFound {
+        segments: Vec<String>,
+    },
+}
+
+pub fn use_app_providers(cx: &ScopeState) {
+    theme::use_theme_provider(cx);
+    i18n::use_locale_provider(cx);
+    layout::use_breakpoint_provider(cx);
+    offline::use_offline_provider(cx);
+    use_context_provider(cx, client);
+}
+
+pub fn App(cx: Scope) -> Element {
+    use_app_providers(cx);
+
+    render! {
+        Router::<Route> {}
+    }
+}
+
+#[inline_props]
+fn PageNotFound(cx: Scope, segments: Vec<String>) -> Element {
+    let locale = i18n::use_locale(cx);
+    let client = use_client(cx);
+    let entities = use_future(cx, (), |_| suggest::entities(client));
+    let routes = suggest::routes(segments);
+    let entity = match entities.value() {
+        Some(Ok(entities)) => suggest::entity(segments, entities),
+        _ => None,
+    };
+    let (did_you_mean, question) = locale
+        .t(Message::DidYouMean)
+        .split_once("{}")
+        .unwrap_or_default();
+
+    render! {
+        h1 { "{locale.t(Message::PageNotFound)}" }
+        p { "{locale.t(Message::PageNotFoundDetail)}" }
+        if let Some(entity) = entity {
+            rsx! {
+                p {
+                    "{did_you_mean}"
+                    Link {
+                        to: entity.route.clone(),
+                        "{entity.name} ({locale.t(entity.list)})"
+                    }
+                    "{question}"
+                }
+            }
+        }
+        if !routes.is_empty() {
+            rsx! {
+                p { "{locale.t(Message::TryInstead)}" }
+                ul {
+                    for route in routes {
+                        li {
+                            Link {
+                                to: route.clone(),
+                                "{route}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Client for the GraphQL server the site was built against.
+fn client() -> f1_client::Client {
+    f1_client::Client::new(env!("GQL_ADDR"))
+}
+
+/// The client provided by [`use_app_providers`], or by the render tests.
+pub fn use_client(cx: &ScopeState) -> f1_client::Client {
+    use_context::<f1_client::Client>(cx)
+        .expect("client is provided")
+        .clone()
+}
@@ -0,0 +1,351 @@
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+
+use crate::{
+    i18n::{self, Locale, LocaleSwitcher, Message},
+    layout::{self, Breakpoint},
+    offline::OfflineBanner,
+    theme::{self, ThemeSwitcher},
+    Route,
+};
+
+impl Route {
+    pub fn label(&self, locale: Locale) -> String {
+        match self {
+            Route::Home {} => locale.t(Message::Home).to_string(),
+            Route::ConstructorsComponent {} => locale.t(Message::Constructors).to_string(),
+            Route::DriversComponent {} => locale.t(Message::Drivers).to_string(),
+            Route::CircuitsComponent {} => locale.t(Message::Schedule).to_string(),
+            Route::CircuitCatalog {} => locale.t(Message::Circuits).to_string(),
+            Route::CircuitDetail { id } => id.replace('_', " "),
+            Route::SeasonConstructors { year } => {
+                format!("{} {year}", locale.t(Message::Constructors))
+            }
+            Route::SeasonDrivers { year } => format!("{} {year}", locale.t(Message::Drivers)),
+            Route::SeasonSchedule { year } => format!("{} {year}", locale.t(Message::Schedule)),
+            Route::SeasonRound { year, round } => {
+                format!("{} {round}, {year}", locale.t(Message::Round))
+            }
+            Route::SeasonSummary { year } => locale.t_with(Message::SeasonSummary, year),
+            Route::SeasonTeammates { year } => {
+                format!("{} {year}", locale.t(Message::TeammateBattles))
+            }
+            Route::PageNotFound { .. } => locale.t(Message::PageNotFound).to_string(),
+        }
+    }
+
+    /// The route a page is shown under, `None` for the home page.
+    fn parent(&self) -> Option<Route> {
+        match self {
+            Route::Home {} => None,
+            Route::ConstructorsComponent {}
+            | Route::DriversComponent {}
+            | Route::CircuitsComponent {}
+            | Route::CircuitCatalog {}
+            | Route::PageNotFound { .. } => Some(Route::Home {}),
+            Route::CircuitDetail { .. } => Some(Route::CircuitCatalog {}),
+            Route::SeasonConstructors { .. } => Some(Route::ConstructorsComponent {}),
+            Route::SeasonDrivers { .. } => Some(Route::DriversComponent {}),
+            Route::SeasonSchedule { .. } => Some(Route::CircuitsComponent {}),
+            Route::SeasonTeammates { year } => Some(Route::SeasonDrivers { year: year.clone() }),
+            Route::SeasonRound { year, .. } | Route::SeasonSummary { year } => {
+                Some(Route::SeasonSchedule { year: year.clone() })
+            }
+        }
+    }
+
+    /// Every route above this one, from the home page down to and including
+    /// `self`.
+    pub fn breadcrumbs(&self) -> Vec<Route> {
+        let mut crumbs = vec![self.clone()];
+        while let Some(parent) = crumbs.last().and_then(Route::parent) {
+            crumbs.push(parent);
+        }
+        crumbs.reverse();
+        crumbs
+    }
+
+    pub fn sections() -> [Route; 5] {
+        [
+            Route::Home {},
+            Route::ConstructorsComponent {},
+            Route::DriversComponent {},
+            Route::CircuitsComponent {},
+            Route::CircuitCatalog {},
+        ]
+    }
+
+    /// The top level section a route is shown under in the nav.
+    fn section(&self) -> Option<Route> {
+        match self {
+            Route::Home {} => Some(Route::Home {}),
+            Route::ConstructorsComponent {} | Route::SeasonConstructors { .. } => {
+                Some(Route::ConstructorsComponent {})
+            }
+            Route::DriversComponent {}
+            | Route::SeasonDrivers { .. }
+            | Route::SeasonTeammates { .. } => Some(Route::DriversComponent {}),
+            Route::CircuitsComponent {}
+            | Route::SeasonSchedule { .. }
+            | Route::SeasonRound { .. }
+            | Route::SeasonSummary { .. } => Some(Route::CircuitsComponent {}),
+            Route::CircuitCatalog {} | Route::CircuitDetail { .. } => {
+                Some(Route::CircuitCatalog {})
+            }
+            Route::PageNotFound { .. } => None,
+        }
+    }
+
+    fn is_within(&self, section: &Route) -> bool {
+        self.section().as_ref() == Some(section)
+    }
+}
+
+#[inline_props]
+pub fn NavBar(cx: Scope) -> Element {
+    let locale = i18n::use_locale(cx);
+    let breakpoint = layout::use_breakpoint(cx);
+    let menu_open = use_state(cx, || false);
+
+    render! {
+        nav {
+            display: "flex",
+            flex_direction: "row",
+            flex_wrap: "wrap",
+            justify_content: "space-between",
+            align_items: "center",
+            background_color: theme::CHROME_BACKGROUND,
+            color: theme::CHROME_TEXT,
+            padding: "10px",
+            if breakpoint == Breakpoint::Mobile {
+                rsx! {
+                    button {
+                        aria_expanded: "{menu_open.get()}",
+                        aria_controls: "nav-menu",
+                        onclick: move |_| menu_open.set(!menu_open.get()),
+                        "☰ {locale.t(Message::Menu)}"
+                    }
+                    if *menu_open.get() {
+                        rsx! {
+                            div {
+                                id: "nav-menu",
+                                width: "100%",
+                                display: "flex",
+                                flex_direction: "column",
+                                gap: "10px",
+                                padding_top: "10px",
+                                div {
+                                    display: "flex",
+                                    flex_direction: "column",
+                                    gap: "10px",
+                                    onclick: move |_| menu_open.set(false),
+                                    NavLinks {}
+                                }
+                                div {
+                                    LocaleSwitcher {}
+                                    ThemeSwitcher {}
+                                }
+                            }
+                        }
+                    }
+                }
+            } else {
+                rsx! {
+                    NavLinks {}
+                    div {
+                        LocaleSwitcher {}
+                        ThemeSwitcher {}
+                    }
+                }
+            }
+        }
+        OfflineBanner {}
+        Breadcrumbs {}
+        Outlet::<Route> {}
+    }
+}
+
+#[inline_props]
+fn NavLinks(cx: Scope) -> Element {
+    let locale = i18n::use_locale(cx);
+    let navigator = use_navigator(cx);
+    let current = use_route::<Route>(cx)?;
+
+    render! {
+        Route::sections().into_iter().map(|section| {
+            let label = section.label(locale);
+            if current.is_within(&section) {
+                let navigator = navigator.clone();
+                // `Link` has no way to mark itself as the current page
+                rsx! {
+                    a {
+                        key: "{section}",
+                        class: "nav-active",
+                        href: "{section}",
+                        aria_current: "page",
+                        prevent_default: "onclick",
+                        onclick: move |_| {
+                            navigator.push(section.clone());
+                        },
+                        "{label}"
+                    }
+                }
+            } else {
+                rsx! {
+                    Link {
+                        key: "{section}",
+                        to: section,
+                        "{label}"
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[inline_props]
+fn Breadcrumbs(cx: Scope) -> Element {
+    let locale = i18n::use_locale(cx);
+    let current = use_route::<Route>(cx)?;
+    let crumbs = current.breadcrumbs();
+    // top level pages are already covered by the nav links
+    if crumbs.len() <= 2 {
+        return None;
+    }
+    let (current, ancestors) = crumbs.split_last()?;
+
+    render! {
+        nav {
+            aria_label: "{locale.t(Message::Breadcrumb)}",
+            padding: "5px 10px",
+            ol {
+                display: "flex",
+                flex_direction: "row",
+                flex_wrap: "wrap",
+                gap: "5px",
+                for crumb in ancestors.iter() {
+                    li {
+                        Link {
+                            to: crumb.clone(),
+                            "{crumb.label(locale)}"
+                        }
+                        span { aria_hidden: "true", " ›" }
+                    }
+                }
+                li {
+                    aria_current: "page",
+                    "{current.label(locale)}"
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing;
+
+    fn paths(route: Route) -> Vec<String> {
+        route.breadcrumbs().iter().map(Route::to_string).collect()
+    }
+
+    fn year() -> String {
+        "2021".to_string()
+    }
+
+    #[test]
+    fn home_is_its_own_breadcrumb() {
+        assert_eq!(paths(Route::Home {}), ["/"]);
+    }
+
+    #[test]
+    fn sections_are_under_home() {
+        assert_eq!(paths(Route::DriversComponent {}), ["/", "/drivers"]);
+        assert_eq!(paths(Route::CircuitCatalog {}), ["/", "/circuits"]);
+    }
+
+    #[test]
+    fn circuits_are_under_the_catalog() {
+        let route = Route::CircuitDetail {
+            id: "monza".to_string(),
+        };
+        assert_eq!(paths(route), ["/", "/circuits", "/circuits/monza"]);
+    }
+
+    #[test]
+    fn seasons_are_under_their_section() {
+        assert_eq!(
+            paths(Route::SeasonConstructors { year: year() }),
+            ["/", "/constructors", "/season/2021/constructors"]
+        );
+        assert_eq!(
+            paths(Route::SeasonDrivers { year: year() }),
+            ["/", "/drivers", "/season/2021/drivers"]
+        );
+        assert_eq!(
+            paths(Route::SeasonSchedule { year: year() }),
+            ["/", "/schedule", "/season/2021/schedule"]
+        );
+    }
+
+    #[test]
+    fn teammates_are_under_the_season_drivers() {
+        assert_eq!(
+            paths(Route::SeasonTeammates { year: year() }),
+            [
+                "/",
+                "/drivers",
+                "/season/2021/drivers",
+                "/season/2021/teammates"
+            ]
+        );
+    }
+
+    #[test]
+    fn rounds_and_summaries_are_under_the_season_schedule() {
+        let round = Route::SeasonRound {
+            year: year(),
+            round: "3".to_string(),
+        };
+        assert_eq!(
+            paths(round),
+            [
+                "/",
+                "/schedule",
+                "/season/2021/schedule",
+                "/season/2021/round/3"
+            ]
+        );
+        assert_eq!(
+            paths(Route::SeasonSummary { year: year() }),
+            [
+                "/",
+                "/schedule",
+                "/season/2021/schedule",
+                "/season/2021/summary"
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_pages_are_under_home() {
+        let route = Route::PageNotFound {
+            segments: vec!["drivrs".to_string()],
+        };
+        assert_eq!(paths(route), ["/", "/drivrs"]);
+    }
+
+    #[tokio::test]
+    async fn marks_the_active_section_as_the_current_page() {
+        let html = testing::render_route(Route::SeasonDrivers {
+            year: testing::YEAR.to_string(),
+        })
+        .await;
+
+        assert!(html.contains(r#"<a class="nav-active" href="/drivers" aria-current="page""#));
+        assert_eq!(html.matches("nav-active").count(), 1);
+        // the other is the last breadcrumb
+        assert_eq!(html.matches(r#"aria-current="page""#).count(), 2);
+    }
+}
@@ -0,0 +1,196 @@
+//! Installs the app: adds the web manifest and registers the service worker
+//! in `public/sw.js`, which caches the app shell and the last response to
+//! every GraphQL query. While it answers from its cache the pages show when
+//! the data was fetched.
+use dioxus::prelude::*;
+use serde_json::Value;
+use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+
+use crate::{
+    browser,
+    i18n::{self, Locale, Message},
+    theme,
+};
+
+/// Where the site is served from, `base_path` in `Dioxus.toml`.
+const BASE_PATH: &str = "/f1graph-web";
+
+/// What the service worker reports after answering a GraphQL request.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum Answer {
+    Network,
+    /// From its cache, fetched at the given milliseconds since the epoch.
+    Cache(f64),
+}
+
+impl Answer {
+    fn parse(text: &str) -> Option<Self> {
+        let message = serde_json::from_str::<Value>(text).ok()?;
+        if message["type"] != "graphql" {
+            return None;
+        }
+        match message["source"].as_str()? {
+            "network" => Some(Answer::Network),
+            "cache" => Some(Answer::Cache(message["fetchedAt"].as_f64()?)),
+            _ => None,
+        }
+    }
+}
+
+/// Whether the data on screen came from the network.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum Connectivity {
+    #[default]
+    Online,
+    /// Showing cached responses, the oldest fetched at the given
+    /// milliseconds since the epoch.
+    Offline(f64),
+}
+
+impl Connectivity {
+    fn answered(self, answer: Answer) -> Self {
+        match (self, answer) {
+            (_, Answer::Network) => Connectivity::Online,
+            (Connectivity::Offline(oldest), Answer::Cache(fetched_at)) => {
+                Connectivity::Offline(oldest.min(fetched_at))
+            }
+            (Connectivity::Online, Answer::Cache(fetched_at)) => Connectivity::Offline(fetched_at),
+        }
+    }
+}
+
+fn add_manifest(document: &web_sys::Document) -> Result<(), JsValue> {
+    let link = document.create_element("link")?;
+    link.set_attribute("rel", "manifest")?;
+    link.set_attribute("href", &format!("{BASE_PATH}/manifest.webmanifest"))?;
+    if let Some(head) = document.query_selector("head")? {
+        head.append_child(&link)?;
+    }
+    Ok(())
+}
+
+/// `None` where service workers aren't available, such as on plain http.
+fn service_worker() -> Option<web_sys::ServiceWorkerContainer> {
+    let navigator = browser::window()?.navigator();
+    let container = JsValue::from(navigator.service_worker());
+    if container.is_undefined() {
+        return None;
+    }
+    Some(container.unchecked_into())
+}
+
+pub fn use_offline_provider(cx: &ScopeState) {
+    use_shared_state_provider(cx, Connectivity::default);
+    let connectivity =
+        use_shared_state::<Connectivity>(cx).expect("connectivity was just provided");
+
+    cx.use_hook(|| {
+        let Some(window) = browser::window() else {
+            return;
+        };
+        if let Some(document) = window.document() {
+            if add_manifest(&document).is_err() {
+                log::warn!("failed to add the web manifest");
+            }
+        }
+        let Some(container) = service_worker() else {
+            return;
+        };
+        // the promise rejects on its own in the console
+        let _ = container.register(&format!("{BASE_PATH}/sw.js"));
+
+        let on_message = {
+            let connectivity = connectivity.clone();
+            Closure::<dyn FnMut(_)>::new(move |event: web_sys::MessageEvent| {
+                let Some(answer) = event
+                    .data()
+                    .as_string()
+                    .and_then(|text| Answer::parse(&text))
+                else {
+                    return;
+                };
+                let next = connectivity.read().answered(answer);
+                if *connectivity.read() != next {
+                    *connectivity.write() = next;
+                }
+            })
+        };
+        let added = container
+            .add_event_listener_with_callback("message", on_message.as_ref().unchecked_ref());
+        if added.is_err() {
+            log::warn!("failed to listen to the service worker");
+        }
+        on_message.forget();
+
+        let connectivity = connectivity.clone();
+        let on_online = Closure::<dyn FnMut()>::new(move || {
+            *connectivity.write() = Connectivity::Online;
+        });
+        let added =
+            window.add_event_listener_with_callback("online", on_online.as_ref().unchecked_ref());
+        if added.is_err() {
+            log::warn!("failed to listen for the connection coming back");
+        }
+        on_online.forget();
+    });
+}
+
+/// `millis` since the epoch as a date and time in the visitor's time zone.
+fn local_time(millis: f64, locale: Locale) -> String {
+    js_sys::Date::new(&JsValue::from_f64(millis))
+        .to_locale_string(locale.as_str(), &JsValue::UNDEFINED)
+        .into()
+}
+
+pub fn OfflineBanner(cx: Scope) -> Element {
+    let locale = i18n::use_locale(cx);
+    let connectivity = use_shared_state::<Connectivity>(cx)?;
+    let Connectivity::Offline(fetched_at) = *connectivity.read() else {
+        return None;
+    };
+
+    render! {
+        p {
+            role: "status",
+            margin: "0",
+            padding: "5px 10px",
+            text_align: "center",
+            background_color: theme::CHROME_BACKGROUND,
+            color: theme::CHROME_TEXT,
+            "{locale.t_with(Message::OfflineCached, &local_time(fetched_at, locale))}"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_service_worker_answers() {
+        assert_eq!(
+            Answer::parse(r#"{"type":"graphql","source":"network"}"#),
+            Some(Answer::Network)
+        );
+        assert_eq!(
+            Answer::parse(r#"{"type":"graphql","source":"cache","fetchedAt":1700000000000}"#),
+            Some(Answer::Cache(1_700_000_000_000.0))
+        );
+        assert_eq!(
+            Answer::parse(r#"{"type":"graphql","source":"cache"}"#),
+            None
+        );
+        assert_eq!(Answer::parse(r#"{"type":"other"}"#), None);
+        assert_eq!(Answer::parse("not json"), None);
+    }
+
+    #[test]
+    fn shows_the_oldest_cached_data_until_back_online() {
+        let connectivity = Connectivity::Online
+            .answered(Answer::Cache(2000.0))
+            .answered(Answer::Cache(1000.0))
+            .answered(Answer::Cache(3000.0));
+        assert_eq!(connectivity, Connectivity::Offline(1000.0));
+        assert_eq!(connectivity.answered(Answer::Network), Connectivity::Online);
+    }
+}
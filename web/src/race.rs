@@ -0,0 +1,320 @@
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+use f1_client::{Client, ConstructorStanding, DriverStanding, Race, RaceResult, Round};
+use futures_util::future::try_join3;
+
+use crate::{
+    a11y::{self, PodiumPosition},
+    catalog::CircuitInfo,
+    flag::Flag,
+    footer,
+    i18n::{self, Message},
+    standings::StandingsTable,
+    theme, use_client, Route,
+};
+
+const RESULT_TABLE_ID: &str = "result";
+const DRIVERS_TABLE_ID: &str = "round-drivers";
+const CONSTRUCTORS_TABLE_ID: &str = "round-constructors";
+
+/// Everything shown for a single round of a season.
+#[derive(PartialEq)]
+struct Weekend {
+    race: Race,
+    previous: Option<Round>,
+    next: Option<Round>,
+    results: Vec<RaceResult>,
+    drivers: Vec<DriverStanding>,
+    constructors: Vec<ConstructorStanding>,
+}
+
+/// `None` when `round` isn't a round of `year`, a round that hasn't been
+/// raced yet has no results or standings.
+async fn weekend(
+    client: &Client,
+    year: &str,
+    round: &str,
+) -> Result<Option<Weekend>, f1_client::Error> {
+    let Ok(round) = Round::try_from(round.to_string()) else {
+        return Ok(None);
+    };
+    let races = client.schedule(year).await?.races;
+    let Some(index) = races.iter().position(|race| race.round == round) else {
+        return Ok(None);
+    };
+    // once the round is known to exist the rest can be asked for at once
+    let (results, drivers, constructors) = try_join3(
+        client.race_result(year, round),
+        client.driver_standings_after(year, round),
+        client.constructor_standings_after(year, round),
+    )
+    .await?;
+
+    Ok(Some(Weekend {
+        race: races[index].clone(),
+        previous: index.checked_sub(1).map(|index| races[index].round),
+        next: races.get(index + 1).map(|race| race.round),
+        results,
+        drivers,
+        constructors,
+    }))
+}
+
+/// Ergast times look like `15:00:00Z`.
+fn race_time(time: &str) -> String {
+    format!("{} UTC", time.get(..5).unwrap_or(time))
+}
+
+#[inline_props]
+pub fn SeasonRound(cx: Scope, year: String, round: String) -> Element {
+    let locale = i18n::use_locale(cx);
+    let client = use_client(cx);
+    let future = use_future(
+        cx,
+        (year.clone(), round.clone()),
+        |(year, round)| async move { weekend(&client, &year, &round).await },
+    );
+
+    cx.render(rsx! {
+        div {
+            display: "flex",
+            flex_direction: "column",
+            align_items: "center",
+            match future.value() {
+                Some(Ok(Some(weekend))) => rsx! { ShowWeekend { year: year, weekend: weekend } },
+                Some(Ok(None)) => rsx! { h1 { "{locale.t(Message::PageNotFound)}" } },
+                Some(Err(_)) => rsx! { div { "{locale.t(Message::Error)}" } },
+                None => rsx! { div { "{locale.t(Message::Loading)}" } },
+            }
+        }
+        footer::Footer {}
+    })
+}
+
+#[derive(PartialEq, Props)]
+struct ShowWeekendProps<'a> {
+    year: &'a String,
+    weekend: &'a Weekend,
+}
+
+fn ShowWeekend<'a>(cx: Scope<'a, ShowWeekendProps<'a>>) -> Element {
+    let locale = i18n::use_locale(cx);
+    let year = cx.props.year;
+    let weekend = cx.props.weekend;
+    let race = &weekend.race;
+    let circuit = &race.circuit;
+    let round = race.round.to_string();
+
+    cx.render(rsx! {
+        h1 {
+            b { "{race.name}" }
+        }
+        p {
+            "{locale.t(Message::Round)} {round} · {locale.format_date(race.date)}"
+            if let Some(time) = &race.time {
+                rsx! { " · {race_time(time)}" }
+            }
+        }
+        p {
+            Link {
+                to: Route::CircuitDetail { id: circuit.id.clone() },
+                "{circuit.name}"
+            }
+        }
+        CircuitInfo { circuit: circuit }
+        nav {
+            aria_label: "{locale.t(Message::Round)}",
+            display: "flex",
+            gap: "20px",
+            if let Some(previous) = weekend.previous {
+                rsx! {
+                    Link {
+                        to: Route::SeasonRound { year: year.clone(), round: previous.to_string() },
+                        "‹ {locale.t(Message::PreviousRound)}"
+                    }
+                }
+            }
+            if let Some(next) = weekend.next {
+                rsx! {
+                    Link {
+                        to: Route::SeasonRound { year: year.clone(), round: next.to_string() },
+                        "{locale.t(Message::NextRound)} ›"
+                    }
+                }
+            }
+        }
+        h2 { "{locale.t(Message::RaceResult)}" }
+        ShowResult { results: &weekend.results }
+        h2 { "{locale.t_with(Message::StandingsAfterRound, &round)}" }
+        StandingsTable {
+            rows: weekend.drivers.as_slice(),
+            table_id: DRIVERS_TABLE_ID,
+            caption: Message::DriversStandings,
+            name_header: Message::Driver,
+        }
+        StandingsTable {
+            rows: weekend.constructors.as_slice(),
+            table_id: CONSTRUCTORS_TABLE_ID,
+            caption: Message::ConstructorsStandings,
+            name_header: Message::Team,
+        }
+    })
+}
+
+#[derive(PartialEq, Props)]
+struct ShowResultProps<'a> {
+    results: &'a Vec<RaceResult>,
+}
+
+fn ShowResult<'a>(cx: Scope<'a, ShowResultProps<'a>>) -> Element {
+    let locale = i18n::use_locale(cx);
+    let results = cx.props.results;
+
+    cx.render(rsx! {
+        table {
+            border_collapse: "collapse",
+            caption {
+                class: a11y::SR_ONLY,
+                "{locale.t(Message::RaceResult)}"
+            }
+            thead {
+                tr {
+                    th { scope: "col", "{locale.t(Message::Position)}" }
+                    th { scope: "col", "{locale.t(Message::Code)}" }
+                    th { scope: "col", "{locale.t(Message::Driver)}" }
+                    th { scope: "col", "{locale.t(Message::Points)}" }
+                }
+            }
+            tbody {
+                for (index, result) in results.iter().enumerate() {
+                    tr {
+                        id: "{a11y::row_id(RESULT_TABLE_ID, index)}",
+                        class: theme::ROW_CLASS,
+                        text_align: "center",
+                        tabindex: "0",
+                        onkeydown: move |event: KeyboardEvent| {
+                            if a11y::navigate_rows(&event.key(), RESULT_TABLE_ID, index, results.len()) {
+                                event.stop_propagation();
+                            }
+                        },
+                        td {
+                            PodiumPosition { position: result.position }
+                        }
+                        td { "{result.driver.code.as_deref().unwrap_or_default()}" }
+                        th {
+                            scope: "row",
+                            Flag { country: result.driver.country() }
+                            match &result.driver.url {
+                                Some(url) => rsx! {
+                                    a {
+                                        href: "{url}",
+                                        target: "_blank",
+                                        "{result.driver.name()}"
+                                    }
+                                },
+                                None => rsx! { "{result.driver.name()}" },
+                            }
+                        }
+                        td { "{locale.format_number(result.points.get())}" }
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{i18n::Locale, testing};
+    use f1_client::FixtureTransport;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn finds_neighbouring_rounds() {
+        let client = testing::client();
+
+        let first = weekend(&client, testing::YEAR, "1").await.unwrap().unwrap();
+        assert_eq!(first.race.name, "Bahrain Grand Prix");
+        assert_eq!(first.previous, None);
+        assert_eq!(first.next.map(|round| round.get()), Some(2));
+        assert_eq!(first.drivers[0].driver.id, "hamilton");
+        assert_eq!(first.constructors[0].points.get(), 41.0);
+
+        assert!(weekend(&client, testing::YEAR, "9")
+            .await
+            .unwrap()
+            .is_none());
+        assert!(weekend(&client, testing::YEAR, "first")
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn shows_unraced_rounds_empty() {
+        // what the backend answers for round 3 before it has been raced
+        let round = json!({ "year": testing::YEAR, "round": "3" });
+        let client = Client::with_transport(
+            FixtureTransport::new()
+                .with(
+                    "Circuits",
+                    json!({ "year": testing::YEAR }),
+                    serde_json::from_str(include_str!("../fixtures/schedule.json")).unwrap(),
+                )
+                .with(
+                    "RoundResults",
+                    round.clone(),
+                    json!({
+                        "data": { "DriversSeasonalRecords": { "season": "2021", "drivers": [] } },
+                    }),
+                )
+                .with(
+                    "Drivers",
+                    round.clone(),
+                    json!({
+                        "data": { "DriverStandings": null },
+                        "errors": [{ "message": "standings not found" }],
+                    }),
+                )
+                .with(
+                    "Constructors",
+                    round,
+                    json!({
+                        "data": { "ConstructorStandings": null },
+                        "errors": [{ "message": "standings not found" }],
+                    }),
+                ),
+        );
+
+        let last = weekend(&client, testing::YEAR, "3").await.unwrap().unwrap();
+        assert_eq!(last.race.name, "Portuguese Grand Prix");
+        assert_eq!(last.previous.map(|round| round.get()), Some(2));
+        assert_eq!(last.next, None);
+        assert!(last.results.is_empty());
+        assert!(last.drivers.is_empty());
+        assert!(last.constructors.is_empty());
+    }
+
+    #[tokio::test]
+    async fn renders_race_weekend() {
+        let html = testing::render_route(Route::SeasonRound {
+            year: testing::YEAR.to_string(),
+            round: "1".to_string(),
+        })
+        .await;
+
+        assert!(!html.contains(Locale::En.t(Message::Loading)));
+        assert!(html.contains("Bahrain Grand Prix"));
+        assert!(html.contains("28 Mar 2021"));
+        assert!(html.contains("15:00 UTC"));
+        assert!(html.contains("Sakhir, Bahrain"));
+        assert!(html.contains(&Locale::En.t_with(Message::StandingsAfterRound, "1")));
+        assert!(html.contains(Locale::En.t(Message::NextRound)));
+        assert!(!html.contains(Locale::En.t(Message::PreviousRound)));
+        // the classification is in finishing order
+        let hamilton = html.find("Lewis Hamilton").unwrap();
+        let verstappen = html.find("Max Verstappen").unwrap();
+        assert!(hamilton < verstappen);
+    }
+}
@@ -0,0 +1,284 @@
+use dioxus::prelude::*;
+use f1_client::{Position, Race, RaceDate, Round};
+use std::{cell::Cell, cmp::Ordering, collections::HashMap, rc::Rc};
+use wasm_bindgen::{prelude::Closure, JsCast};
+
+use crate::browser;
+
+const DEFAULT_INTERVAL_SECS: u32 = 60;
+
+/// Seconds between refreshes of the current season, set with `REFRESH_SECS`
+/// at build time.
+pub fn interval_secs() -> u32 {
+    option_env!("REFRESH_SECS")
+        .and_then(|secs| secs.parse().ok())
+        .filter(|secs| *secs > 0)
+        .unwrap_or(DEFAULT_INTERVAL_SECS)
+}
+
+fn page_hidden() -> bool {
+    browser::window()
+        .and_then(|window| window.document())
+        .is_some_and(|document| document.hidden())
+}
+
+/// A counter that goes up every [`interval_secs`] while `live` is set and the
+/// page is visible, to add to the dependencies of the futures that should
+/// refetch. Refreshes as soon as the page is shown again if it missed one
+/// while hidden. Nothing polls while `live` isn't set.
+pub fn use_refresh(cx: &ScopeState, live: bool) -> u64 {
+    let count = use_state(cx, || 0_u64);
+    let poller = cx.use_hook(|| None::<Poller>);
+    if live && poller.is_none() {
+        *poller = Poller::start(count.clone());
+    } else if !live {
+        *poller = None;
+    }
+
+    *count.get()
+}
+
+/// Whether the standings after the `raced` round of a season with `races`
+/// can still change `today`: from the day the next round is raced until its
+/// results are in the standings. Seasons that are over never change.
+pub fn round_in_progress(races: &[Race], raced: Option<Round>, today: RaceDate) -> bool {
+    races
+        .iter()
+        .find(|race| Some(race.round) > raced)
+        .is_some_and(|next| next.date <= today)
+}
+
+/// The interval timer and visibility listener behind [`use_refresh`], both
+/// removed when it stops polling.
+struct Poller {
+    window: web_sys::Window,
+    document: web_sys::Document,
+    interval: i32,
+    _on_interval: Closure<dyn FnMut()>,
+    on_visibility_change: Closure<dyn FnMut()>,
+}
+
+impl Poller {
+    fn start(count: UseState<u64>) -> Option<Self> {
+        let window = browser::window()?;
+        let document = window.document()?;
+        let missed = Rc::new(Cell::new(false));
+
+        let on_interval = {
+            let (count, missed) = (count.clone(), missed.clone());
+            Closure::<dyn FnMut()>::new(move || {
+                if page_hidden() {
+                    missed.set(true);
+                } else {
+                    count.modify(|count| count + 1);
+                }
+            })
+        };
+        let on_visibility_change = Closure::<dyn FnMut()>::new(move || {
+            if !page_hidden() && missed.replace(false) {
+                count.modify(|count| count + 1);
+            }
+        });
+        let millis = i32::try_from(interval_secs().saturating_mul(1000)).unwrap_or(i32::MAX);
+        let interval = window
+            .set_interval_with_callback_and_timeout_and_arguments_0(
+                on_interval.as_ref().unchecked_ref(),
+                millis,
+            )
+            .map_err(|_| log::warn!("failed to start the refresh timer"))
+            .ok()?;
+        let added = document.add_event_listener_with_callback(
+            "visibilitychange",
+            on_visibility_change.as_ref().unchecked_ref(),
+        );
+        if added.is_err() {
+            log::warn!("failed to listen for page visibility changes");
+        }
+
+        Some(Poller {
+            window,
+            document,
+            interval,
+            _on_interval: on_interval,
+            on_visibility_change,
+        })
+    }
+}
+
+impl Drop for Poller {
+    fn drop(&mut self) {
+        self.window.clear_interval_with_handle(self.interval);
+        let _ = self.document.remove_event_listener_with_callback(
+            "visibilitychange",
+            self.on_visibility_change.as_ref().unchecked_ref(),
+        );
+    }
+}
+
+/// Which way a row moved at the latest refresh.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Movement {
+    Up,
+    Down,
+}
+
+impl Movement {
+    /// Animated in `public/theme.css`.
+    pub fn class(self) -> &'static str {
+        match self {
+            Movement::Up => "moved-up",
+            Movement::Down => "moved-down",
+        }
+    }
+}
+
+/// The positions seen at the previous refresh of a season.
+#[derive(Default)]
+pub struct Positions {
+    season: String,
+    positions: HashMap<String, Position>,
+}
+
+impl Positions {
+    /// How the rows, keyed by id, moved since the previous refresh of the
+    /// same `season`. Nothing moves on the first load of a season.
+    pub fn update(
+        &mut self,
+        season: &str,
+        rows: impl IntoIterator<Item = (String, Position)>,
+    ) -> HashMap<String, Movement> {
+        let positions = rows.into_iter().collect::<HashMap<String, Position>>();
+        let moves = if self.season == season {
+            positions
+                .iter()
+                .filter_map(|(key, position)| {
+                    let movement = match position.cmp(self.positions.get(key)?) {
+                        Ordering::Less => Movement::Up,
+                        Ordering::Greater => Movement::Down,
+                        Ordering::Equal => return None,
+                    };
+                    Some((key.clone(), movement))
+                })
+                .collect()
+        } else {
+            HashMap::new()
+        };
+        self.season = season.to_string();
+        self.positions = positions;
+        moves
+    }
+}
+
+/// The round the standings of a season were up to date with at the previous
+/// refresh.
+#[derive(Default)]
+pub struct LatestRound {
+    season: String,
+    round: Option<Round>,
+}
+
+impl LatestRound {
+    /// Whether `round` of `season` has been raced since the previous refresh
+    /// of the same season. The first load of a season is nothing new.
+    pub fn update(&mut self, season: &str, round: Option<Round>) -> bool {
+        let raced = self.season == season && self.round != round;
+        self.season = season.to_string();
+        self.round = round;
+        raced
+    }
+
+    /// The round the standings were up to date with at the latest refresh.
+    pub fn round(&self) -> Option<Round> {
+        self.round
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use f1_client::Circuit;
+
+    fn date(day: &str) -> RaceDate {
+        day.to_string().try_into().unwrap()
+    }
+
+    fn round(round: &str) -> Option<Round> {
+        Some(Round::try_from(round.to_string()).unwrap())
+    }
+
+    fn race(round: &str, day: &str) -> Race {
+        Race {
+            round: round.to_string().try_into().unwrap(),
+            name: format!("Race {round}"),
+            url: None,
+            date: date(day),
+            time: None,
+            circuit: Circuit {
+                id: String::new(),
+                name: String::new(),
+                url: None,
+                img: None,
+                location: None,
+            },
+        }
+    }
+
+    fn rows(order: &[&str]) -> Vec<(String, Position)> {
+        order
+            .iter()
+            .enumerate()
+            .map(|(index, key)| {
+                let position = (index + 1).to_string().try_into().unwrap();
+                (key.to_string(), position)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn tracks_moves_between_refreshes() {
+        let mut positions = Positions::default();
+
+        assert!(positions
+            .update("current", rows(&["VER", "HAM", "BOT"]))
+            .is_empty());
+        let moves = positions.update("current", rows(&["HAM", "VER", "BOT"]));
+        assert_eq!(moves.len(), 2);
+        assert_eq!(moves["HAM"], Movement::Up);
+        assert_eq!(moves["VER"], Movement::Down);
+        assert!(positions
+            .update("current", rows(&["HAM", "VER", "BOT"]))
+            .is_empty());
+        // a different season isn't a refresh
+        assert!(positions
+            .update("2021", rows(&["VER", "HAM", "BOT"]))
+            .is_empty());
+    }
+
+    #[test]
+    fn notices_new_rounds_between_refreshes() {
+        let mut latest = LatestRound::default();
+
+        assert!(!latest.update("current", round("1")));
+        assert!(!latest.update("current", round("1")));
+        assert!(latest.update("current", round("2")));
+        // a different season isn't a refresh
+        assert!(!latest.update("2021", round("22")));
+    }
+
+    #[test]
+    fn polls_only_while_a_round_is_being_raced() {
+        let races = [race("1", "2021-03-28"), race("2", "2021-04-18")];
+
+        // before the season starts
+        assert!(!round_in_progress(&races, None, date("2021-03-27")));
+        // the day of the first race until its results are in
+        assert!(round_in_progress(&races, None, date("2021-03-28")));
+        assert!(round_in_progress(&races, None, date("2021-03-29")));
+        // between rounds
+        assert!(!round_in_progress(&races, round("1"), date("2021-04-01")));
+        assert!(round_in_progress(&races, round("1"), date("2021-04-18")));
+        // a season that is over
+        assert!(!round_in_progress(&races, round("2"), date("2021-04-19")));
+        assert!(!round_in_progress(&races, round("2"), date("2026-10-19")));
+    }
+}
@@ -0,0 +1,207 @@
+use dioxus::prelude::*;
+use f1_client::{Age, Country, Points, Position};
+use std::collections::HashSet;
+
+use crate::{
+    a11y::{self, PodiumPosition},
+    flag::Flag,
+    i18n::{self, Message},
+    refresh::Movement,
+    theme,
+};
+
+/// A row of a championship table. Implement it to show a new kind of
+/// standings with [`StandingsTable`].
+pub trait StandingRow: PartialEq {
+    fn position(&self) -> Position;
+    fn name(&self) -> String;
+    /// Where the name links to, usually a wikipedia page.
+    fn link(&self) -> Option<&str>;
+    fn points(&self) -> Points;
+    fn wins(&self) -> u32;
+    /// Short code shown next to the name, if the row has one.
+    fn code(&self) -> Option<&str> {
+        None
+    }
+    /// Whose flag is shown before the name.
+    fn country(&self) -> Option<Country> {
+        None
+    }
+    /// Shown in an age column when any row has one.
+    fn age(&self) -> Option<Age> {
+        None
+    }
+    /// Highlights a row that changed position at the latest refresh.
+    fn movement(&self) -> Option<Movement> {
+        None
+    }
+    /// Identifies the row when it's picked for comparison, rows without one
+    /// can't be compared.
+    fn compare_key(&self) -> Option<&str> {
+        None
+    }
+}
+
+#[derive(Props)]
+pub struct StandingsTableProps<'a, T: StandingRow> {
+    rows: &'a [T],
+    /// Prefix for the row ids used by keyboard navigation.
+    table_id: &'static str,
+    caption: Message,
+    name_header: Message,
+    /// Adds a column of checkboxes that pick rows by their compare key.
+    compare: Option<&'a UseState<HashSet<String>>>,
+    /// The compare key of the row under the pointer, shared with a chart.
+    highlight: Option<&'a UseState<Option<String>>>,
+}
+
+pub fn StandingsTable<'a, T: StandingRow>(
+    cx: Scope<'a, StandingsTableProps<'a, T>>,
+) -> Element<'a> {
+    let locale = i18n::use_locale(cx);
+    let rows = cx.props.rows;
+    let has_code = rows.iter().any(|row| row.code().is_some());
+    let has_age = rows.iter().any(|row| row.age().is_some());
+
+    cx.render(rsx! {
+        table {
+            border_collapse: "collapse",
+            caption {
+                class: a11y::SR_ONLY,
+                "{locale.t(cx.props.caption)}"
+            }
+            thead {
+                tr {
+                    if cx.props.compare.is_some() {
+                        rsx! { th { scope: "col", "{locale.t(Message::Compare)}" } }
+                    }
+                    th { scope: "col", "{locale.t(Message::Position)}" }
+                    if has_code {
+                        rsx! { th { scope: "col", "{locale.t(Message::Code)}" } }
+                    }
+                    th { scope: "col", "{locale.t(cx.props.name_header)}" }
+                    if has_age {
+                        rsx! { th { scope: "col", "{locale.t(Message::Age)}" } }
+                    }
+                    th { scope: "col", "{locale.t(Message::Wins)}" }
+                    th { scope: "col", "{locale.t(Message::Points)}" }
+                }
+            }
+            tbody {
+                for (index, row) in rows.iter().enumerate() {
+                    StandingsRow {
+                        row: row,
+                        index: index,
+                        rows: rows.len(),
+                        table_id: cx.props.table_id,
+                        has_code: has_code,
+                        has_age: has_age,
+                        compare: cx.props.compare,
+                        highlight: cx.props.highlight,
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[derive(Props)]
+struct StandingsRowProps<'a, T: StandingRow> {
+    row: &'a T,
+    index: usize,
+    rows: usize,
+    table_id: &'static str,
+    has_code: bool,
+    has_age: bool,
+    #[props(!optional)]
+    compare: Option<&'a UseState<HashSet<String>>>,
+    #[props(!optional)]
+    highlight: Option<&'a UseState<Option<String>>>,
+}
+
+fn StandingsRow<'a, T: StandingRow>(cx: Scope<'a, StandingsRowProps<'a, T>>) -> Element<'a> {
+    let locale = i18n::use_locale(cx);
+    let row = cx.props.row;
+    let name = row.name();
+    let row_id = a11y::row_id(cx.props.table_id, cx.props.index);
+    let movement = row.movement().map(Movement::class).unwrap_or_default();
+    let highlighted = match (cx.props.highlight, row.compare_key()) {
+        (Some(highlight), Some(key)) if highlight.get().as_deref() == Some(key) => "highlighted",
+        _ => "",
+    };
+    let hover = move |key: Option<&str>| {
+        if let (Some(highlight), Some(_)) = (cx.props.highlight, row.compare_key()) {
+            highlight.set(key.map(str::to_string));
+        }
+    };
+
+    cx.render(rsx! {
+        tr {
+            id: "{row_id}",
+            class: "{theme::ROW_CLASS} {movement} {highlighted}",
+            text_align: "center",
+            tabindex: "0",
+            onmouseenter: move |_| hover(row.compare_key()),
+            onmouseleave: move |_| hover(None),
+            onkeydown: move |event: KeyboardEvent| {
+                if a11y::navigate_rows(&event.key(), cx.props.table_id, cx.props.index, cx.props.rows) {
+                    event.stop_propagation();
+                }
+            },
+            if let Some(compare) = cx.props.compare {
+                rsx! {
+                    td {
+                        if let Some(key) = row.compare_key() {
+                            rsx! {
+                                input {
+                                    r#type: "checkbox",
+                                    aria_label: "{locale.t_with(Message::CompareDriver, &name)}",
+                                    onchange: move |event: Event<FormData>| {
+                                        let mut picked = compare.get().clone();
+                                        if event.value == "true" {
+                                            picked.insert(key.to_string());
+                                        } else {
+                                            picked.remove(key);
+                                        }
+                                        compare.set(picked);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            td {
+                PodiumPosition { position: row.position() }
+            }
+            if cx.props.has_code {
+                rsx! { td { "{row.code().unwrap_or_default()}" } }
+            }
+            th {
+                scope: "row",
+                Flag { country: row.country() }
+                match row.link() {
+                    Some(url) => rsx! {
+                        a {
+                            href: "{url}",
+                            target: "_blank",
+                            "{name}"
+                        }
+                    },
+                    None => rsx! { "{name}" },
+                }
+            }
+            if cx.props.has_age {
+                rsx! {
+                    td {
+                        if let Some(age) = row.age() {
+                            rsx! { "{age.years}" }
+                        }
+                    }
+                }
+            }
+            td { "{row.wins()}" }
+            td { "{locale.format_number(row.points().get())}" }
+        }
+    })
+}
@@ -0,0 +1,16 @@
+use crate::browser;
+
+fn local_storage() -> Option<web_sys::Storage> {
+    browser::window()?.local_storage().ok()?
+}
+
+pub fn get(key: &str) -> Option<String> {
+    local_storage()?.get_item(key).ok()?
+}
+
+pub fn set(key: &str, value: &str) {
+    let stored = local_storage().map(|storage| storage.set_item(key, value).is_ok());
+    if stored != Some(true) {
+        log::warn!("failed to persist {key} in local storage");
+    }
+}
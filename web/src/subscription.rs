@@ -0,0 +1,508 @@
+//! Push updates over the `graphql-transport-ws` protocol, with the polling of
+//! [`refresh`](crate::refresh) as the fallback when the server can't push.
+//!
+//! [`Session`] is the protocol without any IO so it can be driven by the
+//! stand-in server in the tests, [`use_live_updates`] connects it to a
+//! browser WebSocket.
+use dioxus::prelude::*;
+use serde_json::{json, Value};
+use std::{cell::RefCell, rc::Rc, time::Duration};
+use wasm_bindgen::{prelude::Closure, JsCast};
+
+use crate::{browser, refresh};
+
+pub const PROTOCOL: &str = "graphql-transport-ws";
+
+/// Tells subscribers the standings of `year` changed, they refetch whatever
+/// they show.
+pub const STANDINGS_UPDATED: &str = "subscription StandingsUpdated($year: String!) {
+    StandingsUpdated(year: $year) {
+        season
+        round
+    }
+}";
+
+/// Close codes the protocol uses for requests the server will never accept,
+/// reconnecting can't help.
+const FATAL_CLOSE_CODES: [u16; 4] = [4400, 4403, 4406, 4500];
+
+/// Consecutive failed connections before giving up on push updates.
+const MAX_ATTEMPTS: u32 = 6;
+
+/// Exponential delays between reconnects, reset once a connection is
+/// acknowledged.
+#[derive(Debug, Default)]
+pub struct Backoff {
+    attempts: u32,
+}
+
+impl Backoff {
+    const FIRST: Duration = Duration::from_secs(1);
+    const MAX: Duration = Duration::from_secs(30);
+
+    /// The delay before the next attempt, `None` once there have been
+    /// [`MAX_ATTEMPTS`].
+    pub fn next(&mut self) -> Option<Duration> {
+        if self.attempts >= MAX_ATTEMPTS {
+            return None;
+        }
+        let delay = Self::FIRST.saturating_mul(1 << self.attempts.min(16));
+        self.attempts += 1;
+        Some(delay.min(Self::MAX))
+    }
+
+    pub fn reset(&mut self) {
+        self.attempts = 0;
+    }
+}
+
+/// What a [`Session`] needs its socket or its user to do.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Action {
+    /// Write a text frame to the socket.
+    Send(String),
+    /// The server acknowledged the connection and the subscriptions are
+    /// live.
+    Connected,
+    /// The server pushed a result for the subscription with this id.
+    Update(String),
+    /// Open a new socket after the delay.
+    Reconnect(Duration),
+    /// Push updates aren't available, poll instead.
+    FallBack,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum State {
+    /// Waiting for the socket to open, or for the server's ack.
+    #[default]
+    Connecting,
+    Acknowledged,
+    Closed,
+}
+
+/// The client side of `graphql-transport-ws`. Subscriptions are sent once
+/// the server acknowledges the connection, and again after every reconnect.
+#[derive(Debug, Default)]
+pub struct Session {
+    state: State,
+    backoff: Backoff,
+    next_id: u64,
+    subscriptions: Vec<(String, Value)>,
+}
+
+impl Session {
+    /// Adds a subscription and returns its id, the frames go out straight
+    /// away when already connected.
+    pub fn subscribe(&mut self, query: &str, variables: Value) -> (String, Vec<Action>) {
+        self.next_id += 1;
+        let id = self.next_id.to_string();
+        let payload = json!({ "query": query, "variables": variables });
+        self.subscriptions.push((id.clone(), payload));
+        let actions = match self.state {
+            State::Acknowledged => vec![self.subscribe_frame(self.subscriptions.len() - 1)],
+            State::Connecting | State::Closed => Vec::new(),
+        };
+        (id, actions)
+    }
+
+    /// The socket opened, starts the handshake.
+    pub fn opened(&mut self) -> Vec<Action> {
+        self.state = State::Connecting;
+        vec![frame(json!({ "type": "connection_init" }))]
+    }
+
+    /// Handles a text frame from the server.
+    pub fn received(&mut self, text: &str) -> Vec<Action> {
+        let Ok(message) = serde_json::from_str::<Value>(text) else {
+            log::warn!("ignoring malformed {PROTOCOL} message");
+            return Vec::new();
+        };
+        let id = message["id"].as_str().unwrap_or_default();
+        match message["type"].as_str() {
+            Some("connection_ack") if self.state == State::Connecting => {
+                self.state = State::Acknowledged;
+                self.backoff.reset();
+                let mut actions = (0..self.subscriptions.len())
+                    .map(|index| self.subscribe_frame(index))
+                    .collect::<Vec<Action>>();
+                actions.push(Action::Connected);
+                actions
+            }
+            Some("ping") => vec![frame(json!({ "type": "pong" }))],
+            Some("next") if self.is_subscribed(id) => vec![Action::Update(id.to_string())],
+            Some("complete") => {
+                self.subscriptions.retain(|(known, _)| known != id);
+                Vec::new()
+            }
+            // the server has no such subscription, it won't have one after
+            // reconnecting either
+            Some("error") => {
+                self.state = State::Closed;
+                vec![Action::FallBack]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// The socket closed, or failed to open, with `code`.
+    pub fn closed(&mut self, code: u16) -> Vec<Action> {
+        if self.state == State::Closed {
+            return Vec::new();
+        }
+        let delay = if FATAL_CLOSE_CODES.contains(&code) {
+            None
+        } else {
+            self.backoff.next()
+        };
+        match delay {
+            Some(delay) => {
+                self.state = State::Connecting;
+                vec![Action::Reconnect(delay)]
+            }
+            None => {
+                self.state = State::Closed;
+                vec![Action::FallBack]
+            }
+        }
+    }
+
+    fn is_subscribed(&self, id: &str) -> bool {
+        self.subscriptions.iter().any(|(known, _)| known == id)
+    }
+
+    fn subscribe_frame(&self, index: usize) -> Action {
+        let (id, payload) = &self.subscriptions[index];
+        frame(json!({ "id": id, "type": "subscribe", "payload": payload }))
+    }
+}
+
+fn frame(message: Value) -> Action {
+    Action::Send(message.to_string())
+}
+
+/// The WebSocket endpoint next to the `/query` endpoint at `gql_addr`.
+pub fn endpoint(gql_addr: &str) -> String {
+    let address = gql_addr.trim_end_matches('/');
+    let address = match address.split_once("://") {
+        Some(("https", rest)) => format!("wss://{rest}"),
+        Some((_, rest)) => format!("ws://{rest}"),
+        None => format!("ws://{address}"),
+    };
+    format!("{address}/query")
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LiveUpdates {
+    /// Goes up with every update, add it to the dependencies of the futures
+    /// that should refetch.
+    pub count: u64,
+    /// Whether the server pushes updates, rather than them being polled.
+    pub pushed: bool,
+}
+
+/// Updates to the current season's standings while `live`. They're pushed by
+/// the server when it can, and polled every refresh interval otherwise.
+pub fn use_live_updates(cx: &ScopeState, live: bool) -> LiveUpdates {
+    let pushed = use_state(cx, || 0_u64);
+    let connected = use_state(cx, || false);
+    let polled = refresh::use_refresh(cx, live && !*connected.get());
+    let subscriber = cx.use_hook(|| None::<Subscriber>);
+
+    if live && subscriber.is_none() {
+        let pushed = pushed.clone();
+        let connected = connected.clone();
+        *subscriber = Subscriber::start(
+            endpoint(env!("GQL_ADDR")),
+            STANDINGS_UPDATED,
+            json!({ "year": "current" }),
+            move |event| match event {
+                Event::Connected => connected.set(true),
+                Event::Update => pushed.modify(|count| count + 1),
+                Event::Disconnected => connected.set(false),
+            },
+        );
+    } else if !live && subscriber.is_some() {
+        *subscriber = None;
+        connected.set(false);
+    }
+
+    LiveUpdates {
+        count: pushed.get() + polled,
+        pushed: *connected.get(),
+    }
+}
+
+enum Event {
+    Connected,
+    Update,
+    Disconnected,
+}
+
+/// One subscription over a browser WebSocket, reconnecting as the session
+/// asks. Dropping it closes the socket.
+///
+/// A socket's handlers stay alive until the next socket replaces it, a
+/// closure mustn't be dropped while it runs.
+struct Subscriber {
+    inner: Rc<RefCell<Inner>>,
+}
+
+struct Inner {
+    url: String,
+    session: Session,
+    socket: Option<Socket>,
+    /// The pending reconnect timeout.
+    retry: Option<i32>,
+    on_event: Box<dyn Fn(Event)>,
+}
+
+struct Socket {
+    socket: web_sys::WebSocket,
+    _on_open: Closure<dyn FnMut()>,
+    _on_message: Closure<dyn FnMut(web_sys::MessageEvent)>,
+    _on_close: Closure<dyn FnMut(web_sys::CloseEvent)>,
+}
+
+impl Drop for Socket {
+    fn drop(&mut self) {
+        self.socket.set_onopen(None);
+        self.socket.set_onmessage(None);
+        self.socket.set_onclose(None);
+        let _ = self.socket.close();
+    }
+}
+
+impl Subscriber {
+    /// `None` outside of a browser.
+    fn start(
+        url: String,
+        query: &str,
+        variables: Value,
+        on_event: impl Fn(Event) + 'static,
+    ) -> Option<Self> {
+        browser::window()?;
+        let mut session = Session::default();
+        // nothing is sent before the socket opens
+        let _ = session.subscribe(query, variables);
+        let inner = Rc::new(RefCell::new(Inner {
+            url,
+            session,
+            socket: None,
+            retry: None,
+            on_event: Box::new(on_event),
+        }));
+        connect(&inner);
+        Some(Subscriber { inner })
+    }
+}
+
+impl Drop for Subscriber {
+    fn drop(&mut self) {
+        let mut inner = self.inner.borrow_mut();
+        inner.socket = None;
+        if let (Some(window), Some(handle)) = (browser::window(), inner.retry.take()) {
+            window.clear_timeout_with_handle(handle);
+        }
+    }
+}
+
+fn connect(inner: &Rc<RefCell<Inner>>) {
+    let url = inner.borrow().url.clone();
+    let socket = match web_sys::WebSocket::new_with_str(&url, PROTOCOL) {
+        Ok(socket) => socket,
+        Err(_) => {
+            log::warn!("failed to open {url}");
+            let actions = inner.borrow_mut().session.closed(1006);
+            run(inner, actions);
+            return;
+        }
+    };
+
+    let weak = Rc::downgrade(inner);
+    let on_open = Closure::<dyn FnMut()>::new(move || {
+        if let Some(inner) = weak.upgrade() {
+            let actions = inner.borrow_mut().session.opened();
+            run(&inner, actions);
+        }
+    });
+    let weak = Rc::downgrade(inner);
+    let on_message = Closure::<dyn FnMut(_)>::new(move |event: web_sys::MessageEvent| {
+        let (Some(inner), Some(text)) = (weak.upgrade(), event.data().as_string()) else {
+            return;
+        };
+        let actions = inner.borrow_mut().session.received(&text);
+        run(&inner, actions);
+    });
+    let weak = Rc::downgrade(inner);
+    let on_close = Closure::<dyn FnMut(_)>::new(move |event: web_sys::CloseEvent| {
+        if let Some(inner) = weak.upgrade() {
+            let actions = inner.borrow_mut().session.closed(event.code());
+            run(&inner, actions);
+        }
+    });
+    socket.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+    socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+    socket.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+
+    inner.borrow_mut().socket = Some(Socket {
+        socket,
+        _on_open: on_open,
+        _on_message: on_message,
+        _on_close: on_close,
+    });
+}
+
+fn run(inner: &Rc<RefCell<Inner>>, actions: Vec<Action>) {
+    for action in actions {
+        match action {
+            Action::Send(text) => {
+                let inner = inner.borrow();
+                let sent = inner
+                    .socket
+                    .as_ref()
+                    .map(|socket| socket.socket.send_with_str(&text));
+                if !matches!(sent, Some(Ok(()))) {
+                    log::warn!("failed to send a {PROTOCOL} message");
+                }
+            }
+            Action::Connected => (inner.borrow().on_event)(Event::Connected),
+            Action::Update(_) => (inner.borrow().on_event)(Event::Update),
+            Action::Reconnect(delay) => {
+                (inner.borrow().on_event)(Event::Disconnected);
+                schedule_reconnect(inner, delay);
+            }
+            Action::FallBack => {
+                log::info!("push updates unavailable, polling instead");
+                if let Some(socket) = &inner.borrow().socket {
+                    let _ = socket.socket.close();
+                }
+                (inner.borrow().on_event)(Event::Disconnected);
+            }
+        }
+    }
+}
+
+fn schedule_reconnect(inner: &Rc<RefCell<Inner>>, delay: Duration) {
+    let Some(window) = browser::window() else {
+        return;
+    };
+    let weak = Rc::downgrade(inner);
+    let retry = Closure::once_into_js(move || {
+        if let Some(inner) = weak.upgrade() {
+            inner.borrow_mut().retry = None;
+            connect(&inner);
+        }
+    });
+    let millis = i32::try_from(delay.as_millis()).unwrap_or(i32::MAX);
+    match window
+        .set_timeout_with_callback_and_timeout_and_arguments_0(retry.unchecked_ref(), millis)
+    {
+        Ok(handle) => inner.borrow_mut().retry = Some(handle),
+        Err(_) => log::warn!("failed to schedule a reconnect"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::StandInServer;
+
+    fn subscribed_session() -> (Session, String) {
+        let mut session = Session::default();
+        let (id, actions) = session.subscribe(STANDINGS_UPDATED, json!({ "year": "current" }));
+        assert!(actions.is_empty(), "nothing is sent before the ack");
+        (session, id)
+    }
+
+    #[test]
+    fn backs_off_exponentially() {
+        let mut backoff = Backoff::default();
+        let delays = std::iter::from_fn(|| backoff.next())
+            .map(|delay| delay.as_secs())
+            .collect::<Vec<u64>>();
+        assert_eq!(delays, [1, 2, 4, 8, 16, 30]);
+        backoff.reset();
+        assert_eq!(backoff.next(), Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn subscribes_once_acknowledged() {
+        let (mut session, id) = subscribed_session();
+        let mut server = StandInServer::default();
+
+        let actions = server.connect(&mut session);
+
+        assert_eq!(actions, [Action::Connected]);
+        assert_eq!(server.subscriptions(), [id.clone()]);
+        assert_eq!(server.push(&mut session, &id), [Action::Update(id)]);
+    }
+
+    #[test]
+    fn answers_pings() {
+        let (mut session, _) = subscribed_session();
+        let mut server = StandInServer::default();
+        server.connect(&mut session);
+
+        assert_eq!(server.ping(&mut session), 1);
+    }
+
+    #[test]
+    fn resubscribes_after_reconnecting() {
+        let (mut session, id) = subscribed_session();
+        let mut server = StandInServer::default();
+        server.connect(&mut session);
+
+        assert_eq!(
+            session.closed(1006),
+            [Action::Reconnect(Duration::from_secs(1))]
+        );
+        let mut server = StandInServer::default();
+        assert_eq!(server.connect(&mut session), [Action::Connected]);
+        assert_eq!(server.subscriptions(), [id]);
+        // the ack reset the backoff
+        assert_eq!(
+            session.closed(1006),
+            [Action::Reconnect(Duration::from_secs(1))]
+        );
+    }
+
+    #[test]
+    fn falls_back_when_the_server_stays_down() {
+        let (mut session, _) = subscribed_session();
+
+        for _ in 0..MAX_ATTEMPTS {
+            assert!(matches!(session.closed(1006)[..], [Action::Reconnect(_)]));
+        }
+        assert_eq!(session.closed(1006), [Action::FallBack]);
+        assert!(session.closed(1006).is_empty());
+    }
+
+    #[test]
+    fn falls_back_when_the_server_has_no_subscriptions() {
+        let (mut session, _) = subscribed_session();
+        let mut server = StandInServer::without_subscriptions();
+
+        assert_eq!(
+            server.connect(&mut session),
+            [Action::Connected, Action::FallBack]
+        );
+        assert_eq!(session.closed(4400), []);
+    }
+
+    #[test]
+    fn gives_up_on_protocol_errors() {
+        let (mut session, _) = subscribed_session();
+
+        assert_eq!(session.closed(4406), [Action::FallBack]);
+    }
+
+    #[test]
+    fn builds_endpoint_from_server_address() {
+        assert_eq!(
+            endpoint("http://localhost:8080"),
+            "ws://localhost:8080/query"
+        );
+        assert_eq!(endpoint("https://f1.example/"), "wss://f1.example/query");
+    }
+}
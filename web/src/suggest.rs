@@ -0,0 +1,183 @@
+use f1_client::Client;
+use std::error::Error;
+
+use crate::{i18n::Message, Route};
+
+/// A driver or constructor of the current season, suggested with a link to
+/// the standings it's listed in as there are no pages of their own.
+#[derive(Clone, PartialEq)]
+pub struct Entity {
+    pub name: String,
+    pub route: Route,
+    /// Labels the link, it goes to the season's list rather than the entity.
+    pub list: Message,
+    keys: Vec<String>,
+}
+
+/// Levenshtein distance between two strings, counted in chars.
+fn distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<char>>();
+    let mut previous = (0..=b.len()).collect::<Vec<usize>>();
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// The distance between `input` and `candidate`, if they're close enough to
+/// be a plausible typo.
+fn closeness(input: &str, candidate: &str) -> Option<usize> {
+    let normalise = |value: &str| value.to_lowercase().replace(['-', '_', ' '], "");
+    let (input, candidate) = (normalise(input), normalise(candidate));
+    let distance = distance(&input, &candidate);
+    let threshold = (candidate.chars().count() / 3).max(1);
+    (distance <= threshold).then_some(distance)
+}
+
+/// Top level routes resembling any of the requested path segments, closest
+/// first.
+pub fn routes(segments: &[String]) -> Vec<Route> {
+    let mut matches = Route::sections()
+        .into_iter()
+        .filter_map(|route| {
+            let path = route.to_string();
+            let name = path.trim_matches('/');
+            if name.is_empty() {
+                return None;
+            }
+            segments
+                .iter()
+                .filter_map(|segment| closeness(segment, name))
+                .min()
+                .map(|distance| (distance, route))
+        })
+        .collect::<Vec<(usize, Route)>>();
+    matches.sort_by_key(|(distance, _)| *distance);
+    matches.into_iter().map(|(_, route)| route).collect()
+}
+
+/// The driver or constructor whose id, code or name best resembles any of the
+/// requested path segments.
+pub fn entity<'a>(segments: &[String], entities: &'a [Entity]) -> Option<&'a Entity> {
+    entities
+        .iter()
+        .filter_map(|entity| {
+            segments
+                .iter()
+                .flat_map(|segment| entity.keys.iter().filter_map(|key| closeness(segment, key)))
+                .min()
+                .map(|distance| (distance, entity))
+        })
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, entity)| entity)
+}
+
+/// Drivers and constructors from the current season.
+pub async fn entities(client: Client) -> Result<Vec<Entity>, Box<dyn Error>> {
+    let drivers = client.driver_standings("current").await?;
+    let constructors = client.constructor_standings("current").await?;
+
+    let drivers = drivers.into_iter().map(|standing| {
+        let driver = standing.driver;
+        let name = driver.name();
+        let keys = [
+            Some(driver.id),
+            driver.code,
+            Some(driver.family_name),
+            Some(name.clone()),
+        ];
+        Entity {
+            name,
+            route: Route::DriversComponent {},
+            list: Message::DriversStandings,
+            keys: keys.into_iter().flatten().collect(),
+        }
+    });
+    let constructors = constructors.into_iter().map(|standing| {
+        let team = standing.team;
+        Entity {
+            keys: vec![team.id, team.name.clone()],
+            name: team.name,
+            route: Route::ConstructorsComponent {},
+            list: Message::ConstructorsStandings,
+        }
+    });
+
+    Ok(drivers.chain(constructors).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segments(segments: &[&str]) -> Vec<String> {
+        segments.iter().map(|segment| segment.to_string()).collect()
+    }
+
+    fn driver(name: &str, keys: &[&str]) -> Entity {
+        Entity {
+            name: name.to_string(),
+            route: Route::DriversComponent {},
+            list: Message::DriversStandings,
+            keys: keys.iter().map(|key| key.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn counts_edits_between_strings() {
+        assert_eq!(distance("drivers", "drivers"), 0);
+        assert_eq!(distance("drivrs", "drivers"), 1);
+        assert_eq!(distance("kitten", "sitting"), 3);
+        assert_eq!(distance("", "ham"), 3);
+        assert_eq!(distance("ham", ""), 3);
+        // in chars rather than bytes
+        assert_eq!(distance("perez", "pérez"), 1);
+    }
+
+    #[test]
+    fn accepts_only_plausible_typos() {
+        assert_eq!(closeness("drivrs", "drivers"), Some(1));
+        assert_eq!(closeness("Max-Verstappen", "max_verstappen"), Some(0));
+        assert_eq!(closeness("hamilton", "drivers"), None);
+        assert_eq!(closeness("", "drivers"), None);
+        // short candidates still allow a single typo
+        assert_eq!(closeness("vre", "ver"), None);
+        assert_eq!(closeness("vdr", "ver"), Some(1));
+    }
+
+    #[test]
+    fn ranks_routes_by_distance() {
+        assert!(routes(&segments(&["drivrs"])) == vec![Route::DriversComponent {}]);
+        // constructors come first in the nav, the closer match is first here
+        assert!(
+            routes(&segments(&["drivers", "constructrs"]))
+                == vec![Route::DriversComponent {}, Route::ConstructorsComponent {}]
+        );
+        assert!(routes(&segments(&["season", "2021", "qualifying"])).is_empty());
+        assert!(routes(&[]).is_empty());
+        assert!(routes(&segments(&[""])).is_empty());
+    }
+
+    #[test]
+    fn suggests_the_closest_entity() {
+        let entities = [
+            driver("Max Verstappen", &["max_verstappen", "VER", "Verstappen"]),
+            driver("Sergio Pérez", &["perez", "PER", "Pérez"]),
+        ];
+
+        let name = |segments: &[&str]| {
+            entity(&self::segments(segments), &entities).map(|entity| entity.name.clone())
+        };
+        assert_eq!(name(&["verstapen"]).as_deref(), Some("Max Verstappen"));
+        assert_eq!(name(&["drivers", "perz"]).as_deref(), Some("Sergio Pérez"));
+        // PER is a typo away from "ver" too, the exact match wins
+        assert_eq!(name(&["ver"]).as_deref(), Some("Max Verstappen"));
+        assert_eq!(name(&["schedule"]), None);
+        assert_eq!(name(&[]), None);
+    }
+}
@@ -0,0 +1,175 @@
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+use f1_client::{Driver, Tally};
+
+use crate::{
+    flag::Flag,
+    footer,
+    i18n::{self, Message},
+    theme, use_client, Route,
+};
+
+#[inline_props]
+pub fn SeasonSummary(cx: Scope, year: String) -> Element {
+    let locale = i18n::use_locale(cx);
+    let client = use_client(cx);
+    let future = use_future(cx, (year.clone(),), |(year,)| async move {
+        client.season_summary(&year).await
+    });
+
+    cx.render(rsx! {
+        div {
+            display: "flex",
+            flex_direction: "column",
+            align_items: "center",
+            h1 {
+                b { "{locale.t_with(Message::SeasonSummary, year)}" }
+            }
+            match future.value() {
+                Some(Ok(summary)) => rsx! { ShowSummary { year: year, summary: summary } },
+                Some(Err(_)) => rsx! { div { "{locale.t(Message::Error)}" } },
+                None => rsx! { div { "{locale.t(Message::Loading)}" } },
+            }
+        }
+        footer::Footer {}
+    })
+}
+
+#[derive(PartialEq, Props)]
+struct ShowSummaryProps<'a> {
+    year: &'a String,
+    summary: &'a f1_client::SeasonSummary,
+}
+
+fn ShowSummary<'a>(cx: Scope<'a, ShowSummaryProps<'a>>) -> Element {
+    let locale = i18n::use_locale(cx);
+    let year = cx.props.year;
+    let summary = cx.props.summary;
+    let tallies = [
+        (Message::MostWins, &summary.most_wins),
+        (Message::MostPodiums, &summary.most_podiums),
+    ];
+
+    cx.render(rsx! {
+        section {
+            aria_label: "{locale.t_with(Message::SeasonSummary, year)}",
+            padding: "10px",
+            background_color: theme::SURFACE,
+            dl {
+                if let Some(champion) = &summary.champion {
+                    rsx! {
+                        dt { "{locale.t(Message::Champion)}" }
+                        dd {
+                            DriverName { driver: &champion.driver }
+                            " · {locale.format_number(champion.points.get())}"
+                        }
+                    }
+                }
+                if let Some(runner_up) = &summary.runner_up {
+                    rsx! {
+                        dt { "{locale.t(Message::RunnerUp)}" }
+                        dd {
+                            DriverName { driver: &runner_up.driver }
+                            " · {locale.format_number(runner_up.points.get())}"
+                        }
+                    }
+                }
+                if let Some(margin) = summary.margin {
+                    rsx! {
+                        dt { "{locale.t(Message::Margin)}" }
+                        dd { "{locale.format_number(margin.get())}" }
+                    }
+                }
+                for (label, tally) in tallies {
+                    if let Some(tally) = tally {
+                        rsx! { TallyRecord { label: label, tally: tally } }
+                    }
+                }
+                if let Some(haul) = &summary.best_round {
+                    rsx! {
+                        dt { "{locale.t(Message::BestRound)}" }
+                        dd {
+                            DriverName { driver: &haul.driver }
+                            " · {locale.format_number(haul.points.get())} · "
+                            Link {
+                                to: Route::SeasonRound { year: year.clone(), round: haul.round.to_string() },
+                                "{locale.t(Message::Round)} {haul.round}"
+                            }
+                        }
+                    }
+                }
+                dt { "{locale.t(Message::DifferentWinners)}" }
+                dd { "{summary.winners}" }
+                if let Some(constructors_champion) = &summary.constructors_champion {
+                    rsx! {
+                        dt { "{locale.t(Message::ConstructorsChampion)}" }
+                        dd {
+                            Flag { country: constructors_champion.team.country() }
+                            b { "{constructors_champion.team.name}" }
+                            " · {locale.format_number(constructors_champion.points.get())}"
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[derive(PartialEq, Props)]
+struct TallyRecordProps<'a> {
+    label: Message,
+    tally: &'a Tally,
+}
+
+fn TallyRecord<'a>(cx: Scope<'a, TallyRecordProps<'a>>) -> Element {
+    let locale = i18n::use_locale(cx);
+    let tally = cx.props.tally;
+
+    cx.render(rsx! {
+        dt { "{locale.t(cx.props.label)}" }
+        dd {
+            DriverName { driver: &tally.driver }
+            " · {tally.count}"
+        }
+    })
+}
+
+#[derive(PartialEq, Props)]
+struct DriverNameProps<'a> {
+    driver: &'a Driver,
+}
+
+fn DriverName<'a>(cx: Scope<'a, DriverNameProps<'a>>) -> Element {
+    let driver = cx.props.driver;
+
+    cx.render(rsx! {
+        Flag { country: driver.country() }
+        b { "{driver.name()}" }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{i18n::Locale, testing};
+
+    #[tokio::test]
+    async fn renders_season_summary() {
+        let html = testing::render_route(Route::SeasonSummary {
+            year: testing::YEAR.to_string(),
+        })
+        .await;
+
+        assert!(!html.contains(Locale::En.t(Message::Loading)));
+        assert!(html.contains("2021 season summary"));
+        assert!(html.contains(Locale::En.t(Message::Champion)));
+        assert!(html.contains("Max Verstappen"));
+        assert!(html.contains(Locale::En.t(Message::Margin)));
+        // Verstappen's ten wins
+        assert!(html.contains(" · 10"));
+        assert!(html.contains(Locale::En.t(Message::DifferentWinners)));
+        // the best haul links to its round
+        assert!(html.contains("/season/2021/round/2"));
+        assert!(html.contains("Mercedes"));
+    }
+}
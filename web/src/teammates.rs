@@ -0,0 +1,199 @@
+use dioxus::prelude::*;
+use dioxus_charts::LineChart;
+use f1_client::TeammateBattle;
+
+use crate::{
+    a11y,
+    flag::Flag,
+    footer,
+    i18n::{self, Message},
+    theme, use_client,
+};
+
+#[inline_props]
+pub fn SeasonTeammates(cx: Scope, year: String) -> Element {
+    let locale = i18n::use_locale(cx);
+    let client = use_client(cx);
+    let future = use_future(cx, (year.clone(),), |(year,)| async move {
+        client.teammate_battles(&year).await
+    });
+
+    cx.render(rsx! {
+        div {
+            display: "flex",
+            flex_direction: "column",
+            align_items: "center",
+            h1 {
+                b { "{locale.t(Message::TeammateBattles)} {year}" }
+            }
+            match future.value() {
+                Some(Ok(battles)) => rsx! {
+                    for battle in battles.iter() {
+                        ShowBattle { battle: battle }
+                    }
+                },
+                Some(Err(_)) => rsx! { div { "{locale.t(Message::Error)}" } },
+                None => rsx! { div { "{locale.t(Message::Loading)}" } },
+            }
+        }
+        footer::Footer {}
+    })
+}
+
+#[derive(PartialEq, Props)]
+struct ShowBattleProps<'a> {
+    battle: &'a TeammateBattle,
+}
+
+fn ShowBattle<'a>(cx: Scope<'a, ShowBattleProps<'a>>) -> Element {
+    let locale = i18n::use_locale(cx);
+    let battle = cx.props.battle;
+    let [first, second] = &battle.drivers;
+    let title = format!(
+        "{} · {} – {}",
+        battle.team.name,
+        first.label(),
+        second.label()
+    );
+    let total = battle.points[0].get() + battle.points[1].get();
+    let share = |index: usize| {
+        if total > 0.0 {
+            (battle.points[index].get() / total * 100.0).round()
+        } else {
+            0.0
+        }
+    };
+    let rows = (0..2).map(|index| (index, &battle.drivers[index]));
+
+    let labels = battle
+        .cumulative
+        .iter()
+        .map(|(round, _)| round.to_string())
+        .collect::<Vec<String>>();
+    let series = (0..2)
+        .map(|index| {
+            battle
+                .cumulative
+                .iter()
+                .map(|(_, totals)| totals[index].get() as f32)
+                .collect::<Vec<f32>>()
+        })
+        .collect::<Vec<Vec<f32>>>();
+    let series_labels = battle
+        .drivers
+        .iter()
+        .map(|driver| driver.label().to_string())
+        .collect::<Vec<String>>();
+
+    cx.render(rsx! {
+        section {
+            aria_label: "{title}",
+            width: "100%",
+            max_width: "600px",
+            padding: "10px",
+            margin_bottom: "20px",
+            background_color: theme::SURFACE,
+            h2 {
+                Flag { country: battle.team.country() }
+                "{title}"
+            }
+            table {
+                border_collapse: "collapse",
+                width: "100%",
+                caption {
+                    class: a11y::SR_ONLY,
+                    "{title}"
+                }
+                thead {
+                    tr {
+                        th { scope: "col", "{locale.t(Message::Driver)}" }
+                        th { scope: "col", "{locale.t(Message::Points)}" }
+                        th { scope: "col", "{locale.t(Message::PointsShare)}" }
+                        th { scope: "col", "{locale.t(Message::RoundsAhead)}" }
+                    }
+                }
+                tbody {
+                    for (index, driver) in rows {
+                        tr {
+                            class: theme::ROW_CLASS,
+                            text_align: "center",
+                            th {
+                                scope: "row",
+                                Flag { country: driver.country() }
+                                "{driver.name()}"
+                            }
+                            td { "{locale.format_number(battle.points[index].get())}" }
+                            td { "{locale.format_number(share(index))}%" }
+                            td { "{battle.ahead[index]} / {battle.shared_rounds()}" }
+                        }
+                    }
+                }
+            }
+            figure {
+                width: "100%",
+                margin: "0",
+                aria_label: "{locale.t(Message::PointsByRound)}",
+                div {
+                    aria_hidden: "true",
+                    LineChart {
+                        series: series.clone(),
+                        labels: labels.clone(),
+                        series_labels: series_labels.clone(),
+                        width: "100%",
+                        height: "100%",
+                        viewbox_width: 600,
+                        viewbox_height: 300,
+                        padding_top: 20,
+                        padding_left: 50,
+                        padding_right: 60,
+                        padding_bottom: 30,
+                    }
+                }
+                table {
+                    class: a11y::SR_ONLY,
+                    caption { "{locale.t(Message::PointsByRound)}" }
+                    thead {
+                        tr {
+                            th { scope: "col", "{locale.t(Message::Code)}" }
+                            for label in labels.iter() {
+                                th { scope: "col", "{locale.t(Message::Round)} {label}" }
+                            }
+                        }
+                    }
+                    tbody {
+                        for (code, points) in series_labels.iter().zip(series.iter()) {
+                            tr {
+                                th { scope: "row", "{code}" }
+                                for total in points.iter() {
+                                    td { "{locale.format_number(f64::from(*total))}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{i18n::Locale, testing, Route};
+
+    #[tokio::test]
+    async fn renders_teammate_battles() {
+        let html = testing::render_route(Route::SeasonTeammates {
+            year: testing::YEAR.to_string(),
+        })
+        .await;
+
+        assert!(!html.contains(Locale::En.t(Message::Loading)));
+        // Verstappen has no teammate in the fixtures
+        assert!(html.contains("Mercedes · HAM – BOT"));
+        assert!(!html.contains("Red Bull"));
+        // Hamilton finished ahead in all three rounds, 69 points to 32
+        assert!(html.contains("3 / 3"));
+        assert!(html.contains("68%"));
+    }
+}
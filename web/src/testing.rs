@@ -0,0 +1,212 @@
+//! Server-side renders components against the canned responses in
+//! `fixtures/`, so they can be tested without a backend at `GQL_ADDR`.
+use dioxus::prelude::*;
+use dioxus_router::prelude::*;
+use f1_client::{Client, FixtureTransport};
+use serde_json::{json, Value};
+use std::{collections::VecDeque, time::Duration};
+
+use crate::{
+    i18n, layout,
+    subscription::{Action, Session},
+    theme, Route,
+};
+
+/// The season the fixtures were captured from.
+pub const YEAR: &str = "2021";
+// fixture responses are ready immediately, so a short wait means they're done
+const IDLE_TIMEOUT: Duration = Duration::from_millis(100);
+
+fn fixture(body: &str) -> Value {
+    serde_json::from_str(body).expect("fixtures are valid json")
+}
+
+pub fn client() -> Client {
+    let season = json!({ "year": YEAR });
+    // standings take an optional round, absent means the whole season
+    let final_round = json!({ "year": YEAR, "round": null });
+    let first_round = json!({ "year": YEAR, "round": "1" });
+    let transport = FixtureTransport::new()
+        .with(
+            "Drivers",
+            final_round.clone(),
+            fixture(include_str!("../fixtures/drivers.json")),
+        )
+        .with(
+            "Drivers",
+            first_round.clone(),
+            fixture(include_str!("../fixtures/drivers_round_1.json")),
+        )
+        .with(
+            "DriversGraph",
+            season.clone(),
+            fixture(include_str!("../fixtures/drivers_graph.json")),
+        )
+        .with(
+            "RoundResults",
+            json!({ "year": YEAR, "round": "1" }),
+            fixture(include_str!("../fixtures/round_results_1.json")),
+        )
+        .with(
+            "Constructors",
+            final_round,
+            fixture(include_str!("../fixtures/constructors.json")),
+        )
+        .with(
+            "Constructors",
+            first_round,
+            fixture(include_str!("../fixtures/constructors_round_1.json")),
+        )
+        .with(
+            "Circuits",
+            season,
+            fixture(include_str!("../fixtures/schedule.json")),
+        )
+        .with(
+            "CircuitCatalog",
+            json!({ "year": "all" }),
+            fixture(include_str!("../fixtures/circuits.json")),
+        )
+        .with(
+            "CircuitRaces",
+            json!({ "id": "bahrain" }),
+            fixture(include_str!("../fixtures/circuit_races.json")),
+        );
+    Client::with_transport(transport)
+}
+
+/// Provides the fixture client and the shared state the components expect
+/// from the app root.
+pub fn use_fixtures(cx: &ScopeState) {
+    i18n::use_locale_provider(cx);
+    layout::use_breakpoint_provider(cx);
+    use_context_provider(cx, client);
+}
+
+/// Renders `root` to HTML once its queries have been answered.
+pub async fn render(root: Component) -> String {
+    settle(VirtualDom::new(root)).await
+}
+
+#[inline_props]
+fn RouteRoot(cx: Scope, route: Route) -> Element {
+    theme::use_theme_provider(cx);
+    use_fixtures(cx);
+    let route = route.clone();
+
+    render! {
+        Router::<Route> {
+            config: move || RouterConfig::default().history(MemoryHistory::with_initial_path(route.clone())),
+        }
+    }
+}
+
+/// Renders the whole app at `route`, for pages that link to others and so
+/// need a router.
+pub async fn render_route(route: Route) -> String {
+    settle(VirtualDom::new_with_props(
+        RouteRoot,
+        RouteRootProps { route },
+    ))
+    .await
+}
+
+async fn settle(mut vdom: VirtualDom) -> String {
+    let _ = vdom.rebuild();
+    while tokio::time::timeout(IDLE_TIMEOUT, vdom.wait_for_work())
+        .await
+        .is_ok()
+    {
+        let _ = vdom.render_immediate();
+    }
+    dioxus_ssr::render(&vdom)
+}
+
+/// Plays the server side of `graphql-transport-ws` to a [`Session`], in place
+/// of a backend with subscriptions.
+pub struct StandInServer {
+    supports_subscriptions: bool,
+    subscriptions: Vec<String>,
+    pongs: usize,
+}
+
+impl Default for StandInServer {
+    fn default() -> Self {
+        StandInServer {
+            supports_subscriptions: true,
+            subscriptions: Vec::new(),
+            pongs: 0,
+        }
+    }
+}
+
+impl StandInServer {
+    /// Answers every subscription with an error, like a schema without a
+    /// subscription type.
+    pub fn without_subscriptions() -> Self {
+        StandInServer {
+            supports_subscriptions: false,
+            ..StandInServer::default()
+        }
+    }
+
+    /// Ids of the subscriptions the session has made.
+    pub fn subscriptions(&self) -> &[String] {
+        &self.subscriptions
+    }
+
+    /// Opens a socket for `session` and runs the handshake, returning what
+    /// the session did besides talking to the server.
+    pub fn connect(&mut self, session: &mut Session) -> Vec<Action> {
+        let actions = session.opened();
+        self.exchange(session, actions)
+    }
+
+    /// Pushes a result for the subscription `id`.
+    pub fn push(&mut self, session: &mut Session, id: &str) -> Vec<Action> {
+        let next = json!({ "id": id, "type": "next", "payload": { "data": {} } });
+        let actions = session.received(&next.to_string());
+        self.exchange(session, actions)
+    }
+
+    /// Pings the session and returns how many pongs have come back so far.
+    pub fn ping(&mut self, session: &mut Session) -> usize {
+        let actions = session.received(&json!({ "type": "ping" }).to_string());
+        self.exchange(session, actions);
+        self.pongs
+    }
+
+    fn exchange(&mut self, session: &mut Session, actions: Vec<Action>) -> Vec<Action> {
+        let mut queue = VecDeque::from(actions);
+        let mut rest = Vec::new();
+        while let Some(action) = queue.pop_front() {
+            let Action::Send(text) = action else {
+                rest.push(action);
+                continue;
+            };
+            let message = fixture(&text);
+            let reply = match message["type"].as_str() {
+                Some("connection_init") => Some(json!({ "type": "connection_ack" })),
+                Some("subscribe") if self.supports_subscriptions => {
+                    let id = message["id"].as_str().unwrap_or_default();
+                    self.subscriptions.push(id.to_string());
+                    None
+                }
+                Some("subscribe") => Some(json!({
+                    "id": message["id"],
+                    "type": "error",
+                    "payload": [{ "message": "subscriptions are not supported" }],
+                })),
+                Some("pong") => {
+                    self.pongs += 1;
+                    None
+                }
+                _ => None,
+            };
+            if let Some(reply) = reply {
+                queue.extend(session.received(&reply.to_string()));
+            }
+        }
+        rest
+    }
+}
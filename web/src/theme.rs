@@ -0,0 +1,133 @@
+use dioxus::prelude::*;
+use wasm_bindgen::{prelude::Closure, JsCast};
+
+use crate::{
+    browser,
+    i18n::{self, Message},
+    storage,
+};
+
+const STORAGE_KEY: &str = "theme";
+const DARK_QUERY: &str = "(prefers-color-scheme: dark)";
+
+// Colours live as CSS custom properties in `public/theme.css`, keyed off the
+// `data-theme` attribute on the document element.
+pub const SURFACE: &str = "var(--surface)";
+pub const CHROME_BACKGROUND: &str = "var(--chrome-background)";
+pub const CHROME_TEXT: &str = "var(--chrome-text)";
+pub const ROW_CLASS: &str = "row border-2 hover:ring-2 hover:ring-inset";
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ThemeMode {
+    Light,
+    Dark,
+    #[default]
+    System,
+}
+
+impl ThemeMode {
+    pub const ALL: [ThemeMode; 3] = [ThemeMode::Light, ThemeMode::Dark, ThemeMode::System];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ThemeMode::Light => "light",
+            ThemeMode::Dark => "dark",
+            ThemeMode::System => "system",
+        }
+    }
+
+    fn message(self) -> Message {
+        match self {
+            ThemeMode::Light => Message::ThemeLight,
+            ThemeMode::Dark => Message::ThemeDark,
+            ThemeMode::System => Message::ThemeSystem,
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        ThemeMode::ALL
+            .into_iter()
+            .find(|mode| mode.as_str() == value)
+    }
+
+    fn load() -> Self {
+        storage::get(STORAGE_KEY)
+            .and_then(|value| ThemeMode::parse(&value))
+            .unwrap_or_default()
+    }
+
+    fn resolve(self) -> &'static str {
+        match self {
+            ThemeMode::System if prefers_dark() => ThemeMode::Dark.as_str(),
+            ThemeMode::System => ThemeMode::Light.as_str(),
+            mode => mode.as_str(),
+        }
+    }
+}
+
+fn dark_query() -> Option<web_sys::MediaQueryList> {
+    browser::window()?.match_media(DARK_QUERY).ok()?
+}
+
+fn prefers_dark() -> bool {
+    dark_query().map(|query| query.matches()).unwrap_or(false)
+}
+
+fn apply(mode: ThemeMode) {
+    let root = browser::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.document_element());
+    if let Some(root) = root {
+        if root.set_attribute("data-theme", mode.resolve()).is_err() {
+            log::warn!("failed to apply {} theme", mode.as_str());
+        }
+    }
+}
+
+pub fn use_theme_provider(cx: &ScopeState) {
+    use_shared_state_provider(cx, ThemeMode::load);
+    let mode = use_shared_state::<ThemeMode>(cx).expect("theme was just provided");
+
+    // follow the OS setting live while the user hasn't picked a theme
+    cx.use_hook(|| {
+        let Some(query) = dark_query() else {
+            return;
+        };
+        let mode = mode.clone();
+        let listener = Closure::<dyn FnMut()>::new(move || {
+            if *mode.read() == ThemeMode::System {
+                apply(ThemeMode::System);
+            }
+        });
+        query.set_onchange(Some(listener.as_ref().unchecked_ref()));
+        listener.forget();
+    });
+
+    let current = *mode.read();
+    use_effect(cx, (&current,), |(current,)| async move { apply(current) });
+}
+
+pub fn ThemeSwitcher(cx: Scope) -> Element {
+    let locale = i18n::use_locale(cx);
+    let mode = use_shared_state::<ThemeMode>(cx)?;
+    let current = *mode.read();
+
+    render! {
+        select {
+            aria_label: "{locale.t(Message::Theme)}",
+            onchange: move |event| {
+                if let Some(next) = ThemeMode::parse(&event.value) {
+                    storage::set(STORAGE_KEY, next.as_str());
+                    *mode.write() = next;
+                }
+            },
+            for candidate in ThemeMode::ALL {
+                option {
+                    value: candidate.as_str(),
+                    selected: candidate == current,
+                    "{locale.t(candidate.message())}"
+                }
+            }
+        }
+    }
+}